@@ -0,0 +1,70 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Extensions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class ActionDescriptionTests
+    {
+        [TestMethod]
+        public void EveryActionHasADistinctDescription()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var victim = state.Players[1];
+            var field = state.Fields[1];
+            var card = new Card(Rank.Five, Suit.Summer);
+            var other = new Card(Rank.Two, Suit.Spring);
+            var pile = new Pile(new List<Card> { other, new Card(Rank.Three, Suit.Winter) });
+
+            var actions = new List<IAction>
+            {
+                new SowCards(player, new List<Card> { card }, field),
+                new HarvestCards(player, card, field, new List<Pile> { pile }),
+                new StockpileCards(player, card, field, new List<Pile> { pile }),
+                new ExchangeCard(player, field, card, other),
+                new ChangelingClaimExchange(player, new[] { new ExchangeCard(player, field, card, other) }),
+                new ChangelingExchange(player, field, card, other),
+                new ScatterStockpile(player, field, pile),
+                new ChangeSeason(player, Season.Summer, 1),
+                new BeginTurn(player),
+                new DrawUp(player),
+                new DealHand(player, victim, 4),
+                new SeedField(game, field),
+                new InitialDeal(player),
+                new DealLuminary(game, field),
+                new DiscardLuminary(game, field),
+                new RevealLuminary(game, field),
+                new CollectLuminary(player, field),
+                new CollectOkus(player, new Okus(player, "A")),
+                new PlaceOkus(player),
+                new StealOkus(player, victim),
+                new StealCardFromHand(player, victim),
+                new GiveCardFromHarvestPile(player, victim),
+                new ScoreRound(game)
+            };
+            var descriptions = actions.Select(x => x.ToString()).ToList();
+
+            Assert.IsTrue(descriptions.All(x => !string.IsNullOrWhiteSpace(x)));
+            Assert.AreEqual(descriptions.Count, descriptions.Distinct().Count());
+        }
+
+        [TestMethod]
+        public void DescribeNamesTheFieldAnActionTouched()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var sow = new SowCards(state.Players[0], new List<Card> { new Card(Rank.Five, Suit.Summer) }, state.Fields[1]);
+
+            var description = state.Describe(sow);
+
+            StringAssert.Contains(description, "Five of Summer");
+            StringAssert.Contains(description, "field 1 (Summer)");
+        }
+    }
+}
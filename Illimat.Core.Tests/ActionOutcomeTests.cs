@@ -0,0 +1,96 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class ActionOutcomeTests
+    {
+        [TestMethod]
+        public void PlayingAKingReportsTheRotation()
+        {
+            var game = TestGames.New();
+            var state = game.State!;
+            var player = state.Players[0];
+            var king = new Card(Rank.King, Suit.Autumn);
+            player.Hand.Add(king);
+
+            var outcome = game.Perform(new SowCards(player, new List<Card> { king }, state.Fields[1]));
+
+            Assert.AreEqual((Season.Spring, Season.Summer), outcome.SeasonChange);
+            Assert.IsFalse(outcome.FieldCleared);
+            Assert.AreEqual(0, outcome.LuminariesRevealed.Count);
+        }
+
+        [TestMethod]
+        public void PlayingAThreeLeavesTheIllimatAlone()
+        {
+            var game = TestGames.New();
+            var state = game.State!;
+            var player = state.Players[0];
+            var three = new Card(Rank.Three, Suit.Autumn);
+            player.Hand.Add(three);
+
+            var outcome = game.Perform(new SowCards(player, new List<Card> { three }, state.Fields[1]));
+
+            Assert.IsNull(outcome.SeasonChange);
+        }
+
+        [TestMethod]
+        public void ClearingAFieldIsReported()
+        {
+            var game = TestGames.New();
+            var state = game.State!;
+            var player = state.Players[0];
+            var five = new Card(Rank.Five, Suit.Stars);
+            var target = new Pile(new List<Card> { new Card(Rank.Five, Suit.Winter, true) });
+            state.Fields[1].Piles.Add(target);
+            player.Hand.Add(five);
+
+            var outcome = game.Perform(new HarvestCards(player, five, state.Fields[1], new List<Pile> { target }));
+
+            Assert.IsTrue(outcome.FieldCleared);
+            Assert.IsNull(outcome.SeasonChange);
+        }
+
+        [TestMethod]
+        public void RevealedLuminariesAreListed()
+        {
+            var game = TestGames.New();
+            var state = game.State!;
+            var maiden = new Luminary(LuminaryName.TheMaiden);
+            state.Fields[2].Luminary = maiden;
+
+            var outcome = game.Perform(new RevealLuminary(game, state.Fields[2]));
+
+            CollectionAssert.AreEqual(new[] { maiden }, outcome.LuminariesRevealed.ToList());
+        }
+
+        [TestMethod]
+        public void OutcomesListTheirEventsInOrder()
+        {
+            var game = TestGames.New();
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            summer.Luminary = new Luminary(LuminaryName.TheMaiden);
+            var queen = new Card(Rank.Queen, Suit.Spring);
+            var target = new Pile(new List<Card> { new Card(Rank.Queen, Suit.Autumn, true) });
+            var okus = new Okus(state.Players[1], "Player 1's okus");
+            summer.Piles.Add(target);
+            state.IllimatOkuses.Add(okus);
+            player.Hand.Add(queen);
+
+            var harvest = game.Perform(new HarvestCards(player, queen, summer, new List<Pile> { target }));
+            var collection = game.Perform(new CollectOkus(player, okus));
+
+            CollectionAssert.AreEqual(
+                new[] { GameEventType.CardsHarvested, GameEventType.SeasonChanged, GameEventType.FieldCleared, GameEventType.LuminaryRevealed },
+                harvest.Events.Select(x => x.Type).ToArray());
+            CollectionAssert.AreEqual(new[] { GameEventType.OkusCollected }, collection.Events.Select(x => x.Type).ToArray());
+        }
+    }
+}
@@ -0,0 +1,85 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class BoatTests
+    {
+        [TestMethod]
+        public void SummerBoatClearsBothFieldsInOneHarvest()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var winter = state.Fields[3];
+            summer.Luminary = new Luminary(LuminaryName.TheBoat) { IsRevealed = true };
+            var five = new Card(Rank.Five, Suit.Stars);
+            var near = TestGames.LoosePile(Rank.Five, Suit.Autumn);
+            var far = TestGames.LoosePile(Rank.Five, Suit.Winter);
+            summer.Piles.Add(near);
+            winter.Piles.Add(far);
+            player.Hand.Add(five);
+            var events = new List<GameEvent>();
+            game.EventRaised += events.Add;
+
+            var harvest = new HarvestCards(player, five, summer, new List<Pile> { near }) { OppositePiles = new List<Pile> { far } };
+            harvest.Perform(state);
+
+            Assert.AreEqual(2, harvest.ClearedFieldCount);
+            Assert.AreEqual(2, events.Count(x => x.Type == GameEventType.FieldCleared));
+            Assert.AreEqual(3, player.HarvestPile.Count);
+
+            harvest.Unwind(state);
+
+            CollectionAssert.AreEqual(new[] { far }, winter.Piles);
+            CollectionAssert.AreEqual(new[] { near }, summer.Piles);
+        }
+
+        [TestMethod]
+        public void HarvestingAcrossNeedsTheBoat()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var five = new Card(Rank.Five, Suit.Stars);
+            var near = TestGames.LoosePile(Rank.Five, Suit.Autumn);
+            var far = TestGames.LoosePile(Rank.Five, Suit.Winter);
+            state.Fields[1].Piles.Add(near);
+            state.Fields[3].Piles.Add(far);
+            player.Hand.Add(five);
+
+            var harvest = new HarvestCards(player, five, state.Fields[1], new List<Pile> { near }) { OppositePiles = new List<Pile> { far } };
+
+            var error = Assert.ThrowsException<IllimatException>(() => harvest.Perform(state));
+            Assert.AreEqual(IllimatError.LuminaryUnavailable, error.Error);
+        }
+
+        [TestMethod]
+        public void WinterBoatBlocksHarvestingEvenWithTheMaiden()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var winter = state.Fields[3];
+            var boat = new Luminary(LuminaryName.TheBoat) { IsRevealed = true };
+            winter.Luminary = boat;
+            state.Fields[0].Luminary = new Luminary(LuminaryName.TheMaiden) { IsRevealed = true };
+            var five = new Card(Rank.Five, Suit.Stars);
+            var target = TestGames.LoosePile(Rank.Five, Suit.Autumn);
+            winter.Piles.Add(target);
+            player.Hand.Add(five);
+            var harvest = new HarvestCards(player, five, winter, new List<Pile> { target });
+
+            Assert.IsFalse(state.CanHarvest(winter));
+            Assert.AreSame(boat, state.BlockingLuminary(harvest));
+            var error = Assert.ThrowsException<IllimatException>(() => harvest.Perform(state));
+            Assert.AreEqual(IllimatError.SeasonBlocked, error.Error);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System;
+using System.Collections.Generic;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class ChangelingExchangeTests
+    {
+        [TestMethod]
+        public void FaceUpChangelingSwapsHandCardWithLooseCard()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var field = state.Fields[0];
+            var handCard = new Card(Rank.Two, Suit.Spring);
+            var fieldCard = new Card(Rank.Nine, Suit.Autumn, true);
+            field.Luminary = new Luminary(LuminaryName.TheChangeling) { IsRevealed = true };
+            field.Piles.Add(new Pile(new List<Card> { fieldCard }));
+            player.Hand.Add(handCard);
+            var exchange = new ChangelingExchange(player, field, handCard, fieldCard);
+
+            exchange.Perform(state);
+
+            CollectionAssert.Contains(player.Hand, fieldCard);
+            Assert.AreEqual(handCard, field.Piles[0].Cards[0]);
+
+            exchange.Unwind(state);
+
+            CollectionAssert.Contains(player.Hand, handCard);
+            Assert.IsNull(state.ChangelingExchangeTurn);
+        }
+
+        [TestMethod]
+        public void ChangelingCannotExchangeStockpiledCard()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var field = state.Fields[0];
+            var handCard = new Card(Rank.Two, Suit.Spring);
+            var stockpiledCard = new Card(Rank.Four, Suit.Autumn, true);
+            field.Luminary = new Luminary(LuminaryName.TheChangeling) { IsRevealed = true };
+            field.Piles.Add(new Pile(new List<Card> { stockpiledCard, new Card(Rank.Five, Suit.Winter, true) }));
+            player.Hand.Add(handCard);
+
+            var error = Assert.ThrowsException<IllimatException>(() =>
+                new ChangelingExchange(player, field, handCard, stockpiledCard).Perform(state));
+            Assert.AreEqual(IllimatError.StockpiledCard, error.Error);
+            Assert.IsNull(state.ChangelingExchangeTurn);
+        }
+
+        [TestMethod]
+        public void ChangelingExchangesOncePerTurn()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var handCards = new List<Card> { new Card(Rank.Two, Suit.Spring), new Card(Rank.Three, Suit.Summer) };
+            var fieldCards = new List<Card> { new Card(Rank.Nine, Suit.Autumn, true), new Card(Rank.Ten, Suit.Winter, true) };
+            player.ScorePileLuminaries.Add(new Luminary(LuminaryName.TheChangeling) { IsRevealed = true });
+            player.Hand.AddRange(handCards);
+            state.Fields[0].Piles.Add(new Pile(new List<Card> { fieldCards[0] }));
+            state.Fields[2].Piles.Add(new Pile(new List<Card> { fieldCards[1] }));
+
+            new ChangelingExchange(player, state.Fields[0], handCards[0], fieldCards[0]).Perform(state);
+
+            var error = Assert.ThrowsException<IllimatException>(() =>
+                new ChangelingExchange(player, state.Fields[2], handCards[1], fieldCards[1]).Perform(state));
+            Assert.AreEqual(IllimatError.AlreadyUsedThisTurn, error.Error);
+
+            state.TurnNumber++;
+            new ChangelingExchange(player, state.Fields[2], handCards[1], fieldCards[1]).Perform(state);
+
+            CollectionAssert.AreEquivalent(fieldCards, player.Hand);
+        }
+
+        [TestMethod]
+        public void ChangelingMustBeFaceUpOrClaimed()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var field = state.Fields[0];
+            var handCard = new Card(Rank.Two, Suit.Spring);
+            var fieldCard = new Card(Rank.Nine, Suit.Autumn, true);
+            field.Luminary = new Luminary(LuminaryName.TheChangeling);
+            field.Piles.Add(new Pile(new List<Card> { fieldCard }));
+            player.Hand.Add(handCard);
+
+            var error = Assert.ThrowsException<IllimatException>(() =>
+                new ChangelingExchange(player, field, handCard, fieldCard).Perform(state));
+            Assert.AreEqual(IllimatError.LuminaryUnavailable, error.Error);
+        }
+    }
+}
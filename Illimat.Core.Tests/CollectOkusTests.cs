@@ -0,0 +1,76 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class CollectOkusTests
+    {
+        private static (GameState State, Player Player, List<Okus> Okuses) ClearSummer(Game game)
+        {
+            var state = game.State!;
+            var player = state.Players[0];
+            var five = new Card(Rank.Five, Suit.Summer);
+            var target = new Pile(new List<Card> { new Card(Rank.Five, Suit.Autumn, true) });
+            var okuses = new List<Okus> { new Okus(state.Players[1], "A"), new Okus(state.Players[2], "B") };
+            state.Fields[1].Piles.Add(target);
+            state.IllimatOkuses.AddRange(okuses);
+            player.Hand.Add(five);
+
+            game.Perform(new HarvestCards(player, five, state.Fields[1], new List<Pile> { target }));
+
+            return (state, player, okuses);
+        }
+
+        [TestMethod]
+        public void ClearingAFieldEarnsOneOkus()
+        {
+            var game = TestGames.New();
+            var (state, player, okuses) = ClearSummer(game);
+
+            game.Perform(new CollectOkus(player, okuses[0]));
+
+            CollectionAssert.AreEqual(new[] { okuses[0] }, player.ScorePileOkuses);
+            var error = Assert.ThrowsException<IllimatException>(() => game.Perform(new CollectOkus(player, okuses[1])));
+            Assert.AreEqual(IllimatError.OkusNotEarned, error.Error);
+
+            game.UndoLast();
+            game.Perform(new CollectOkus(player, okuses[1]));
+
+            CollectionAssert.AreEqual(new[] { okuses[1] }, player.ScorePileOkuses);
+        }
+
+        [TestMethod]
+        public void OkusCantBeCollectedWithoutAClear()
+        {
+            var game = TestGames.New();
+            var state = game.State!;
+            var player = state.Players[0];
+            var okus = new Okus(state.Players[1], "A");
+            state.IllimatOkuses.Add(okus);
+
+            var error = Assert.ThrowsException<IllimatException>(() => game.Perform(new CollectOkus(player, okus)));
+
+            Assert.AreEqual(IllimatError.OkusNotEarned, error.Error);
+            CollectionAssert.AreEqual(new[] { okus }, state.IllimatOkuses);
+        }
+
+        [TestMethod]
+        public void OnlyTheClearingPlayerCollectsAndOnlyThatTurn()
+        {
+            var game = TestGames.New();
+            var (state, _, okuses) = ClearSummer(game);
+            var other = state.Players[1];
+
+            var error = Assert.ThrowsException<IllimatException>(() => game.Perform(new CollectOkus(other, okuses[0])));
+            Assert.AreEqual(IllimatError.OkusNotEarned, error.Error);
+
+            game.Perform(new BeginTurn(other));
+
+            error = Assert.ThrowsException<IllimatException>(() => game.Perform(new CollectOkus(state.Players[0], okuses[0])));
+            Assert.AreEqual(IllimatError.OkusNotEarned, error.Error);
+        }
+    }
+}
@@ -0,0 +1,77 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class DealLuminaryTests
+    {
+        [TestMethod]
+        public void LuminariesAreDealtFaceDownByDefault()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+
+            new DealLuminary(game, state.Fields[0]).Perform(state);
+
+            Assert.IsNotNull(state.Fields[0].Luminary);
+            Assert.IsFalse(state.Fields[0].Luminary!.IsRevealed);
+        }
+
+        [TestMethod]
+        public void LuminariesAreDealtFaceUpWhenRevealedAtDeal()
+        {
+            var game = new Game(seed: 1, config: new GameConfig { LuminaryRevealMode = LuminaryRevealMode.AtDeal });
+            var state = game.State!;
+
+            foreach (var field in state.Fields)
+            {
+                new DealLuminary(game, field).Perform(state);
+            }
+
+            foreach (var field in state.Fields)
+            {
+                Assert.IsTrue(field.Luminary!.IsRevealed);
+            }
+        }
+
+        [TestMethod]
+        public void CustomLuminariesAreTheOnlyOnesDealt()
+        {
+            var chosen = new[] { LuminaryName.TheBoat, LuminaryName.TheLoom, LuminaryName.TheUnion, LuminaryName.TheMaiden, LuminaryName.TheRiver };
+            var game = new Game(seed: 1, config: new GameConfig { Luminaries = chosen });
+            var state = game.State!;
+
+            foreach (var field in state.Fields)
+            {
+                new DealLuminary(game, field).Perform(state);
+            }
+
+            CollectionAssert.AreEqual(chosen.Take(4).ToList(), state.Fields.Select(x => x.Luminary!.LuminaryName).ToList());
+            CollectionAssert.AreEqual(new[] { LuminaryName.TheRiver }, state.LuminaryDeck.Cards.Select(x => x.LuminaryName).ToList());
+        }
+
+        [TestMethod]
+        public void LuminarySetsCombineIntoOneDeck()
+        {
+            var config = new GameConfig().WithLuminaries(LuminarySet.BaseLuminaries, LuminarySet.TheCraneWifeLuminaries);
+            var state = new Game(seed: 1, config: config).State!;
+
+            Assert.AreEqual(LuminarySet.BaseLuminaries.Count + LuminarySet.TheCraneWifeLuminaries.Count, state.LuminaryDeck.Cards.Count);
+            Assert.IsFalse(state.LuminaryDeck.Cards.Any(x => LuminarySet.PromoLuminaries.Contains(x.LuminaryName)));
+        }
+
+        [TestMethod]
+        public void OversizedOrTooSmallLuminarySetsAreRejected()
+        {
+            var oversized = new GameConfig().WithLuminaries(LuminarySet.AllLuminaries, new[] { LuminaryName.TheBoat });
+            var tooSmall = new GameConfig { Luminaries = new[] { LuminaryName.TheBoat, LuminaryName.TheLoom } };
+
+            Assert.ThrowsException<ArgumentException>(() => new Game(seed: 1, config: oversized));
+            Assert.ThrowsException<ArgumentException>(() => new Game(seed: 1, config: tooSmall));
+        }
+    }
+}
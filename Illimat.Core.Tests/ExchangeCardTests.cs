@@ -0,0 +1,167 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class ExchangeCardTests
+    {
+        private static void ClaimChangeling(GameState state, Player player)
+        {
+            var winter = state.Fields[3];
+            winter.Luminary = new Luminary(LuminaryName.TheChangeling) { IsRevealed = true };
+
+            new CollectLuminary(player, winter).Perform(state);
+        }
+
+        private static ChangelingClaimExchange ClaimExchange(Player player, params (Field Field, Card Lose, Card Gain)[] swaps) =>
+            new(player, swaps.Select(x => new ExchangeCard(player, x.Field, x.Lose, x.Gain)).ToList());
+
+        [TestMethod]
+        public void TwoExchangesSwapHandCardsWithLooseFieldCards()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var handCards = new List<Card> { new Card(Rank.Two, Suit.Spring), new Card(Rank.Three, Suit.Summer) };
+            var fieldCards = new List<Card> { new Card(Rank.Nine, Suit.Autumn, true), new Card(Rank.Ten, Suit.Winter, true) };
+            player.Hand.AddRange(handCards);
+            state.Fields[0].Piles.Add(new Pile(new List<Card> { fieldCards[0] }));
+            state.Fields[2].Piles.Add(new Pile(new List<Card> { fieldCards[1] }));
+            var seasons = Array.ConvertAll(state.Fields, x => x.Season);
+            ClaimChangeling(state, player);
+
+            ClaimExchange(player, (state.Fields[0], handCards[0], fieldCards[0]), (state.Fields[2], handCards[1], fieldCards[1])).Perform(state);
+
+            CollectionAssert.AreEquivalent(fieldCards, player.Hand);
+            Assert.AreEqual(handCards[0], state.Fields[0].Piles[0].Cards[0]);
+            Assert.AreEqual(handCards[1], state.Fields[2].Piles[0].Cards[0]);
+            CollectionAssert.AreEqual(seasons, Array.ConvertAll(state.Fields, x => x.Season));
+        }
+
+        [TestMethod]
+        public void ExchangeRejectsStockpiledCard()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var handCards = new List<Card> { new Card(Rank.Two, Suit.Spring), new Card(Rank.Three, Suit.Summer) };
+            var looseCard = new Card(Rank.Nine, Suit.Autumn, true);
+            var stockpiledCard = new Card(Rank.Four, Suit.Autumn, true);
+            player.Hand.AddRange(handCards);
+            state.Fields[0].Piles.Add(new Pile(new List<Card> { looseCard }));
+            state.Fields[0].Piles.Add(new Pile(new List<Card> { stockpiledCard, new Card(Rank.Five, Suit.Winter, true) }));
+            ClaimChangeling(state, player);
+
+            var exchange = ClaimExchange(player, (state.Fields[0], handCards[0], looseCard), (state.Fields[0], handCards[1], stockpiledCard));
+
+            var error = Assert.ThrowsException<IllimatException>(() => exchange.Perform(state));
+            Assert.AreEqual(IllimatError.StockpiledCard, error.Error);
+            // The loose card's exchange is checked and refused along with the other, so neither is made.
+            CollectionAssert.AreEqual(handCards, player.Hand);
+            Assert.AreEqual(looseCard, state.Fields[0].Piles[0].Cards.Single());
+            Assert.AreEqual(2, state.Fields[0].Piles[1].Cards.Count);
+        }
+
+        [TestMethod]
+        public void ExchangeCandidatesOfferOnlyLooseCards()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var handCard = new Card(Rank.Two, Suit.Spring);
+            var looseCard = new Card(Rank.Nine, Suit.Summer, true);
+            var stockpiledCard = new Card(Rank.Four, Suit.Autumn, true);
+            player.Hand.Add(handCard);
+            state.Fields[0].Piles.Add(new Pile(new List<Card> { stockpiledCard, new Card(Rank.Five, Suit.Winter, true) }) { Creator = player });
+            state.Fields[0].Piles.Add(new Pile(new List<Card> { looseCard }));
+            ClaimChangeling(state, player);
+
+            CollectionAssert.AreEqual(new[] { looseCard }, state.ExchangeCandidates(state.Fields[0]).ToArray());
+
+            ClaimExchange(player, (state.Fields[0], handCard, looseCard)).Perform(state);
+
+            CollectionAssert.Contains(player.Hand, looseCard);
+        }
+
+        [TestMethod]
+        public void ExchangeIsRejectedOutsideTheChangelingClaimWindow()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var handCards = new List<Card> { new Card(Rank.Two, Suit.Spring), new Card(Rank.Three, Suit.Summer), new Card(Rank.Four, Suit.Stars) };
+            var fieldCards = new List<Card> { new Card(Rank.Nine, Suit.Autumn, true), new Card(Rank.Ten, Suit.Winter, true), new Card(Rank.Knight, Suit.Spring, true) };
+            var spring = state.Fields[0];
+            player.Hand.AddRange(handCards);
+            fieldCards.ForEach(x => spring.Piles.Add(new Pile(new List<Card> { x })));
+
+            var unclaimed = Assert.ThrowsException<IllimatException>(() =>
+                ClaimExchange(player, (spring, handCards[0], fieldCards[0])).Perform(state));
+
+            ClaimChangeling(state, player);
+            var other = state.Players[1];
+            var otherCard = new Card(Rank.Five, Suit.Autumn);
+            other.Hand.Add(otherCard);
+            var byAnotherPlayer = Assert.ThrowsException<IllimatException>(() =>
+                ClaimExchange(other, (spring, otherCard, fieldCards[0])).Perform(state));
+            var tooMany = Assert.ThrowsException<IllimatException>(() =>
+                ClaimExchange(player, (spring, handCards[0], fieldCards[0]), (spring, handCards[1], fieldCards[1]), (spring, handCards[2], fieldCards[2])).Perform(state));
+            ClaimExchange(player, (spring, handCards[0], fieldCards[0]), (spring, handCards[1], fieldCards[1])).Perform(state);
+            var third = Assert.ThrowsException<IllimatException>(() =>
+                ClaimExchange(player, (spring, handCards[2], fieldCards[2])).Perform(state));
+
+            Assert.AreEqual(IllimatError.LuminaryUnavailable, unclaimed.Error);
+            Assert.AreEqual(IllimatError.LuminaryUnavailable, byAnotherPlayer.Error);
+            Assert.AreEqual(IllimatError.InvalidCardCount, tooMany.Error);
+            Assert.AreEqual(IllimatError.LuminaryUnavailable, third.Error);
+            CollectionAssert.Contains(player.Hand, handCards[2]);
+        }
+
+        [TestMethod]
+        public void ClaimExchangesLapseWithTheTurn()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var handCard = new Card(Rank.Two, Suit.Spring);
+            var fieldCard = new Card(Rank.Nine, Suit.Autumn, true);
+            player.Hand.Add(handCard);
+            state.Fields[0].Piles.Add(new Pile(new List<Card> { fieldCard }));
+            ClaimChangeling(state, player);
+            state.TurnNumber++;
+
+            var error = Assert.ThrowsException<IllimatException>(() =>
+                ClaimExchange(player, (state.Fields[0], handCard, fieldCard)).Perform(state));
+
+            Assert.AreEqual(IllimatError.LuminaryUnavailable, error.Error);
+        }
+
+        [TestMethod]
+        public void UndoingTheClaimExchangeUndoesBothSwapsAndRestoresThePiles()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var handCards = new List<Card> { new Card(Rank.Two, Suit.Spring), new Card(Rank.Three, Suit.Summer), new Card(Rank.Four, Suit.Stars) };
+            var piles = new List<Pile> { TestGames.LoosePile(Rank.Nine, Suit.Autumn), TestGames.LoosePile(Rank.Ten, Suit.Winter) };
+            player.Hand.AddRange(handCards);
+            state.Fields[0].Piles.Add(piles[0]);
+            state.Fields[2].Piles.Add(piles[1]);
+            ClaimChangeling(state, player);
+            var window = state.ChangelingClaimExchanges;
+
+            game.Perform(ClaimExchange(player, (state.Fields[0], handCards[1], piles[0].Cards[0]), (state.Fields[2], handCards[0], piles[1].Cards[0])));
+            game.UndoLast();
+
+            CollectionAssert.AreEqual(handCards, player.Hand);
+            Assert.AreSame(piles[0], state.Fields[0].Piles.Single());
+            Assert.AreSame(piles[1], state.Fields[2].Piles.Single());
+            Assert.AreEqual(window, state.ChangelingClaimExchanges);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class ExplainIllegalTests
+    {
+        private static (GameState State, Player Player) NewPosition()
+        {
+            var state = new Game(seed: 1).State!;
+            return (state, state.ActivePlayer);
+        }
+
+        [TestMethod]
+        public void LegalMoveHasNoReasons()
+        {
+            var (state, player) = NewPosition();
+            var five = new Card(Rank.Five, Suit.Summer);
+            player.Hand.Add(five);
+
+            Assert.IsNull(state.ExplainIllegal(player, new SowCards(player, new List<Card> { five }, state.Fields[1])));
+        }
+
+        [TestMethod]
+        public void SowReportsTheSeasonAndTheMissingCardTogether()
+        {
+            var (state, player) = NewPosition();
+            var five = new Card(Rank.Five, Suit.Summer);
+
+            var reasons = state.ExplainIllegal(player, new SowCards(player, new List<Card> { five }, state.Fields[2]))!;
+
+            Assert.AreEqual(2, reasons.Count);
+            StringAssert.Contains(reasons[0], "during Autumn");
+            StringAssert.Contains(reasons[1], "hand");
+        }
+
+        [TestMethod]
+        public void WaitingPlayerIsToldItIsNotTheirTurn()
+        {
+            var (state, _) = NewPosition();
+            var waiting = state.Players[(state.ActivePlayerIndex + 1) % state.Players.Count];
+            var five = new Card(Rank.Five, Suit.Summer);
+            waiting.Hand.Add(five);
+
+            var reasons = state.ExplainIllegal(waiting, new SowCards(waiting, new List<Card> { five }, state.Fields[1]))!;
+
+            StringAssert.Contains(reasons.Single(), "not " + waiting.Name + "'s turn");
+        }
+
+        [TestMethod]
+        public void IslandIsNamedAsTheBlocker()
+        {
+            var (state, player) = NewPosition();
+            var summer = state.Fields[1];
+            summer.Luminary = new Luminary(LuminaryName.TheIsland) { IsRevealed = true };
+            var five = new Card(Rank.Five, Suit.Summer);
+            var target = TestGames.LoosePile(Rank.Five, Suit.Spring);
+            summer.Piles.Add(target);
+            player.Hand.Add(five);
+
+            var reasons = state.ExplainIllegal(player, new HarvestCards(player, five, summer, new List<Pile> { target }))!;
+
+            StringAssert.Contains(reasons.Single(), "because of The Island");
+        }
+
+        [TestMethod]
+        public void FreshStockpileAndWrongSumAreReported()
+        {
+            var (state, player) = NewPosition();
+            var summer = state.Fields[1];
+            var five = new Card(Rank.Five, Suit.Summer);
+            var fresh = new Pile(new List<Card> { new Card(Rank.Two, Suit.Spring, true), new Card(Rank.Four, Suit.Autumn, true) })
+            {
+                Creator = player,
+                CreatedTurn = state.TurnNumber
+            };
+            summer.Piles.Add(fresh);
+            player.Hand.Add(five);
+
+            var reasons = state.ExplainIllegal(player, new HarvestCards(player, five, summer, new List<Pile> { fresh }))!;
+
+            Assert.AreEqual(2, reasons.Count);
+            StringAssert.Contains(reasons[0], "turn it was made");
+            StringAssert.Contains(reasons[1], "value of 5");
+        }
+
+        [TestMethod]
+        public void StockpileWithoutAMatchingCardIsReported()
+        {
+            var (state, player) = NewPosition();
+            var summer = state.Fields[1];
+            var two = new Card(Rank.Two, Suit.Summer);
+            var three = TestGames.LoosePile(Rank.Three, Suit.Spring);
+            summer.Piles.Add(three);
+            player.Hand.AddRange(new[] { two, new Card(Rank.Nine, Suit.Winter) });
+
+            var reasons = state.ExplainIllegal(player, new StockpileCards(player, two, summer, new List<Pile> { three }))!;
+
+            StringAssert.Contains(reasons.Single(), "worth 5");
+        }
+    }
+}
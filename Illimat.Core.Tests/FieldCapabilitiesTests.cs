@@ -0,0 +1,82 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class FieldCapabilitiesTests
+    {
+        [TestMethod]
+        public void CapabilitiesFoldInMaidenAndIsland()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            state.Fields[0].Luminary = new Luminary(LuminaryName.TheMaiden) { IsRevealed = true };
+            state.Fields[1].Luminary = new Luminary(LuminaryName.TheIsland) { IsRevealed = true };
+
+            var capabilities = state.GetFieldCapabilities();
+
+            Assert.AreEqual(new FieldCapabilities(true, true, false), capabilities[0]);
+            Assert.AreEqual(new FieldCapabilities(false, false, false,
+                new[] { "The Island: this field is cut off from play." }), capabilities[1]);
+            Assert.AreEqual(new FieldCapabilities(false, true, true), capabilities[2]);
+            Assert.AreEqual(new FieldCapabilities(true, true, true,
+                new[] { "The Maiden: Winter fields can be harvested." }), capabilities[3]);
+        }
+
+        [TestMethod]
+        public void IslandIsReportedAsBlockingASummerHarvest()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var five = new Card(Rank.Five, Suit.Summer);
+            var island = new Luminary(LuminaryName.TheIsland) { IsRevealed = true };
+            state.Fields[1].Luminary = island;
+            var harvest = new HarvestCards(player, five, state.Fields[1], new List<Pile>());
+            var sow = new SowCards(player, new List<Card> { five }, state.Fields[3]);
+
+            Assert.AreSame(island, state.BlockingLuminary(harvest));
+            Assert.IsNull(state.BlockingLuminary(sow));
+        }
+
+        private static GameConfig MaidenInSpring(LuminaryRevealMode revealMode) => new()
+        {
+            LuminaryRevealMode = revealMode,
+            Luminaries = new[] { LuminaryName.TheMaiden, LuminaryName.TheRake, LuminaryName.TheUnion, LuminaryName.TheLoom }
+        };
+
+        [TestMethod]
+        public void LuminaryDealtFaceUpGrantsItsCapabilityFromTheFirstTurn()
+        {
+            var state = TestGames.Dealt(config: MaidenInSpring(LuminaryRevealMode.AtDeal)).State!;
+
+            Assert.AreEqual(1, state.TurnNumber);
+            Assert.IsTrue(state.Fields[0].Luminary!.IsRevealed);
+            Assert.IsTrue(state.GetFieldCapabilities()[3].CanHarvest);
+        }
+
+        [TestMethod]
+        public void FaceDownLuminaryGrantsNothingUntilItsFieldIsCleared()
+        {
+            var game = TestGames.Dealt(config: MaidenInSpring(LuminaryRevealMode.OnFieldClear));
+            var state = game.State!;
+            var player = state.ActivePlayer;
+            var spring = state.Fields[0];
+            var five = new Card(Rank.Five, Suit.Summer);
+            spring.Piles.Clear();
+            spring.Piles.Add(TestGames.LoosePile(Rank.Five, Suit.Spring));
+            player.Hand.Add(five);
+
+            Assert.IsFalse(state.GetFieldCapabilities()[3].CanHarvest);
+
+            game.Perform(new HarvestCards(player, five, spring, spring.Piles.ToList()));
+
+            Assert.IsTrue(spring.Luminary!.IsRevealed);
+            Assert.IsTrue(state.GetFieldCapabilities()[3].CanHarvest);
+        }
+    }
+}
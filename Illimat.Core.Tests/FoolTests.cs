@@ -0,0 +1,77 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class FoolTests
+    {
+        [TestMethod]
+        public void FoolCanBeOneOrFourteen()
+        {
+            CollectionAssert.AreEquivalent(new[] { 1, 14 }, new Card(Rank.Fool, Suit.Spring).HarvestValues().ToArray());
+            CollectionAssert.AreEqual(new[] { 7 }, new Card(Rank.Seven, Suit.Spring).HarvestValues().ToArray());
+        }
+
+        [TestMethod]
+        public void FoolHarvestsTwoCardsSummingToFourteen()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var fool = new Card(Rank.Fool, Suit.Summer);
+            var six = TestGames.LoosePile(Rank.Six, Suit.Spring);
+            var eight = TestGames.LoosePile(Rank.Eight, Suit.Autumn);
+            summer.Piles.AddRange(new[] { six, eight });
+            player.Hand.Add(fool);
+
+            var harvest = new HarvestCards(player, fool, summer, new List<Pile> { six, eight });
+            harvest.Perform(state);
+
+            Assert.IsTrue(harvest.FieldCleared);
+            Assert.AreEqual(3, player.HarvestPile.Count);
+        }
+
+        [TestMethod]
+        public void FoolHarvestsAnotherFoolAsOne()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var fool = new Card(Rank.Fool, Suit.Summer);
+            var otherFool = TestGames.LoosePile(Rank.Fool, Suit.Winter);
+            var seven = TestGames.LoosePile(Rank.Seven, Suit.Autumn);
+            summer.Piles.AddRange(new[] { otherFool, seven });
+            player.Hand.Add(fool);
+
+            new HarvestCards(player, fool, summer, new List<Pile> { otherFool }).Perform(state);
+
+            CollectionAssert.AreEqual(new[] { seven }, summer.Piles.ToArray());
+            Assert.AreEqual(2, player.HarvestPile.Count);
+        }
+
+        [TestMethod]
+        public void FoolCannotHarvestASeven()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var fool = new Card(Rank.Fool, Suit.Summer);
+            var seven = TestGames.LoosePile(Rank.Seven, Suit.Autumn);
+            summer.Piles.Add(seven);
+            player.Hand.Add(fool);
+
+            var error = Assert.ThrowsException<IllimatException>(() =>
+                new HarvestCards(player, fool, summer, new List<Pile> { seven }).Perform(state));
+
+            Assert.AreEqual(IllimatError.NoMatchingHarvest, error.Error);
+            Assert.AreEqual(0, state.GetHarvestOptions(fool, summer).Count);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class GameEventTests
+    {
+        [TestMethod]
+        public void ClearingHarvestWithFaceCardRaisesEventsInOrder()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var queen = new Card(Rank.Queen, Suit.Spring);
+            var target = TestGames.LoosePile(Rank.Queen, Suit.Autumn);
+            summer.Piles.Add(target);
+            player.Hand.Add(queen);
+            var events = new List<GameEvent>();
+            game.EventRaised += events.Add;
+
+            new HarvestCards(player, queen, summer, new List<Pile> { target }).Perform(state);
+
+            CollectionAssert.AreEqual(
+                new[] { GameEventType.CardsHarvested, GameEventType.SeasonChanged, GameEventType.FieldCleared },
+                events.Select(x => x.Type).ToArray());
+            Assert.IsTrue(events.All(x => x.Actor == player));
+        }
+
+        [TestMethod]
+        public void SowingANumberCardRaisesOnlyCardsSown()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var two = new Card(Rank.Two, Suit.Spring);
+            player.Hand.Add(two);
+            var events = new List<GameEvent>();
+            game.EventRaised += events.Add;
+
+            new SowCards(player, new List<Card> { two }, state.Fields[1]).Perform(state);
+
+            Assert.AreEqual(GameEventType.CardsSown, events.Single().Type);
+            StringAssert.Contains(events.Single().Description, "field 1");
+        }
+
+        [TestMethod]
+        public void ScoringTheRoundRaisesScoreChangesThenRoundEnded()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            state.Players[1].AddToHarvest(new List<Card> { new Card(Rank.Two, Suit.Summer) });
+            state.Players[3].AddToHarvest(new List<Card> { new Card(Rank.Three, Suit.Winter), new Card(Rank.Four, Suit.Winter) });
+            var events = new List<GameEvent>();
+            game.EventRaised += events.Add;
+
+            new ScoreRound(game).Perform(state);
+
+            CollectionAssert.AreEqual(
+                new[] { GameEventType.ScoreChanged, GameEventType.ScoreChanged, GameEventType.RoundEnded },
+                events.Select(x => x.Type).ToArray());
+            Assert.AreSame(state.Players[1], events[0].Actor);
+            Assert.AreSame(state.Players[3], events[1].Actor);
+            Assert.AreSame(game, events[2].Actor);
+            StringAssert.Contains(events[0].Description, "scored 2");
+        }
+    }
+}
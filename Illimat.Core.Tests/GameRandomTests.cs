@@ -0,0 +1,78 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class GameRandomTests
+    {
+        [TestMethod]
+        public void RestoredRandomContinuesWhereTheOriginalLeftOff()
+        {
+            var original = new GameRandom(42);
+            for (int i = 0; i < 65; i++) original.Next(i + 1);
+            original.Next();
+            original.NextDouble();
+
+            var restored = GameRandom.Restore(original.Seed, original.SampleCount);
+
+            for (int i = 0; i < 20; i++)
+            {
+                Assert.AreEqual(original.Next(100), restored.Next(100));
+            }
+        }
+
+        [TestMethod]
+        public void GameRandomCountsDeckShuffleDraws()
+        {
+            var game = new Game(seed: 7);
+            var random = game.Random;
+
+            var restored = GameRandom.Restore(random.Seed, random.SampleCount);
+            var deck = new Deck<Card>(Card.GetCards(SuitSet.AllSuits));
+            var restoredDeck = new Deck<Card>(Card.GetCards(SuitSet.AllSuits));
+            deck.Shuffle(random);
+            restoredDeck.Shuffle(restored);
+
+            CollectionAssert.AreEqual(deck.Cards.ToList(), restoredDeck.Cards.ToList());
+        }
+
+        private static void PlayRandomTurns(Game game, System.Random random, int turns)
+        {
+            var state = game.State!;
+
+            for (int i = 0; i < turns; i++)
+            {
+                var actions = state.LegalActions(state.ActivePlayer).Where(x => x is not ChangelingExchange).ToList();
+                game.Perform(actions[random.Next(actions.Count)]);
+
+                if (!game.AdvanceTurn()) return;
+            }
+        }
+
+        [TestMethod]
+        public void GameResumedMidRoundDrawsAsIfNeverInterrupted()
+        {
+            var uninterrupted = TestGames.Dealt(7);
+            PlayRandomTurns(uninterrupted, uninterrupted.Random, 16);
+
+            var interrupted = TestGames.Dealt(7);
+            PlayRandomTurns(interrupted, interrupted.Random, 8);
+            var saved = GameReplay.Record(interrupted).ToJson();
+
+            var resumed = GameReplay.FromJson(saved).Replay();
+            Assert.AreEqual(interrupted.Random.SampleCount, resumed.Random.SampleCount);
+            PlayRandomTurns(resumed, resumed.Random, 8);
+
+            CollectionAssert.AreEqual(uninterrupted.ReplayMoves.ToList(), resumed.ReplayMoves.ToList());
+            Assert.AreEqual(uninterrupted.State!.BoardKey(), resumed.State!.BoardKey());
+            Assert.AreEqual(uninterrupted.Random.SampleCount, resumed.Random.SampleCount);
+            for (int i = 0; i < 10; i++)
+            {
+                Assert.AreEqual(uninterrupted.Random.Next(1000), resumed.Random.Next(1000));
+            }
+        }
+    }
+}
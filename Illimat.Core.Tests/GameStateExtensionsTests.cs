@@ -0,0 +1,56 @@
+using Illimat.Core.Extensions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class GameStateExtensionsTests
+    {
+        [TestMethod]
+        public void BoardStringShowsEachFieldsSeasonAndCardCount()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            state.Fields[1].Piles.Add(new Pile(new List<Card> { new Card(Rank.Two, Suit.Spring, true) }));
+            state.Fields[2].Piles.Add(new Pile(new List<Card> { new Card(Rank.Three, Suit.Summer, true) }));
+            state.Fields[2].Piles.Add(new Pile(new List<Card> { new Card(Rank.Four, Suit.Winter, true) }));
+            state.Fields[3].Luminary = new Luminary(LuminaryName.TheMaiden);
+
+            var board = state.ToBoardString();
+
+            StringAssert.Contains(board, "0: Spring (0 cards)");
+            StringAssert.Contains(board, "1: Summer (1 card)");
+            StringAssert.Contains(board, "2: Autumn (2 cards)");
+            StringAssert.Contains(board, "3: Winter (0 cards) <Luminary>");
+        }
+
+        [TestMethod]
+        public void BoardStringMatchesAFixedPosition()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            state.Fields[0].Piles.Add(new Pile(new List<Card> { new Card(Rank.Two, Suit.Spring, true) }));
+            state.Fields[1].Luminary = new Luminary(LuminaryName.TheRiver) { IsRevealed = true };
+            state.Fields[3].Luminary = new Luminary(LuminaryName.TheMaiden);
+
+            var expected =
+                "                               0: Spring (1 card)\n" +
+                "3: Winter (0 cards) <Luminary> [ ILLIMAT ] 1: Summer (0 cards) <The River>\n" +
+                "                               2: Autumn (0 cards)\n";
+
+            Assert.AreEqual(expected, state.ToBoardString());
+        }
+
+        [TestMethod]
+        public void BoardStringShowsTheSeasonAFieldPlaysAs()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            state.Fields[2].Luminary = new Luminary(LuminaryName.TheForestQueen) { IsRevealed = true };
+
+            StringAssert.Contains(state.ToBoardString(), "2: Autumn as Summer (0 cards) <The Forest Queen>");
+        }
+    }
+}
@@ -0,0 +1,470 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class GameStateTests
+    {
+        [TestMethod]
+        public void CanClearFieldWhenOneCardHarvestsEveryPile()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            summer.Piles.Add(TestGames.LoosePile(Rank.Five, Suit.Spring));
+            summer.Piles.Add(TestGames.LoosePile(Rank.Two, Suit.Autumn));
+            summer.Piles.Add(TestGames.LoosePile(Rank.Three, Suit.Winter));
+            player.Hand.Add(new Card(Rank.Five, Suit.Summer));
+
+            Assert.IsTrue(state.CanClearField(player, summer));
+        }
+
+        [TestMethod]
+        public void CannotClearFieldWithLeftoverPiles()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            summer.Piles.Add(TestGames.LoosePile(Rank.Five, Suit.Spring));
+            summer.Piles.Add(TestGames.LoosePile(Rank.Seven, Suit.Autumn));
+            player.Hand.Add(new Card(Rank.Five, Suit.Summer));
+
+            Assert.IsFalse(state.CanClearField(player, summer));
+        }
+
+        [TestMethod]
+        public void CannotClearWinterField()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var winter = state.Fields[3];
+            winter.Piles.Add(TestGames.LoosePile(Rank.Five, Suit.Spring));
+            player.Hand.Add(new Card(Rank.Five, Suit.Summer));
+
+            Assert.IsFalse(state.CanClearField(player, winter));
+        }
+
+        [TestMethod]
+        public void TempoRisesWhenEveryFieldIsSummer()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            player.Hand.Add(new Card(Rank.Five, Suit.Summer));
+            player.Hand.Add(new Card(Rank.Two, Suit.Spring));
+            state.Fields[0].Piles.Add(TestGames.LoosePile(Rank.Five, Suit.Autumn));
+
+            foreach (var field in state.Fields) field.Season = Season.Autumn;
+            var lockedDownTempo = state.Tempo(player);
+            foreach (var field in state.Fields) field.Season = Season.Summer;
+            var summerTempo = state.Tempo(player);
+
+            Assert.IsTrue(summerTempo > lockedDownTempo);
+        }
+
+        [TestMethod]
+        public void TempoOnlyCountsStockpilesAnotherCardCanHarvest()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            player.Hand.Add(new Card(Rank.Two, Suit.Spring));
+            player.Hand.Add(new Card(Rank.Three, Suit.Summer));
+            state.Fields[0].Piles.Add(TestGames.LoosePile(Rank.Four, Suit.Autumn));
+            foreach (var field in state.Fields) field.Season = Season.Winter;
+
+            // Both cards can be sown anywhere, but neither stockpile, 6 or 7, has a card to harvest it.
+            Assert.AreEqual(8, state.Tempo(player));
+
+            player.Hand.Add(new Card(Rank.Six, Suit.Autumn));
+
+            // A third card sows four more ways and lets the Two stockpile for the Six.
+            Assert.AreEqual(13, state.Tempo(player));
+        }
+
+        [TestMethod]
+        public void HarvestOptionsListEveryDistinctTargetSet()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var summer = state.Fields[1];
+            var two = TestGames.LoosePile(Rank.Two, Suit.Spring);
+            var three = TestGames.LoosePile(Rank.Three, Suit.Autumn);
+            var five = TestGames.LoosePile(Rank.Five, Suit.Winter);
+            var seven = TestGames.LoosePile(Rank.Seven, Suit.Winter);
+            summer.Piles.AddRange(new[] { two, three, five, seven });
+
+            var options = state.GetHarvestOptions(new Card(Rank.Five, Suit.Summer), summer);
+
+            Assert.AreEqual(3, options.Count);
+            Assert.IsTrue(options.Any(x => x.Count == 1 && x.Contains(five)));
+            Assert.IsTrue(options.Any(x => x.Count == 2 && x.Contains(two) && x.Contains(three)));
+            Assert.IsTrue(options.Any(x => x.Count == 3 && !x.Contains(seven)));
+        }
+
+        [TestMethod]
+        public void HandSizesShrinkAfterSowingAndHarvesting()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var two = new Card(Rank.Two, Suit.Spring);
+            var five = new Card(Rank.Five, Suit.Summer);
+            player.Hand.AddRange(new[] { two, five, new Card(Rank.Nine, Suit.Winter) });
+            summer.Piles.Add(TestGames.LoosePile(Rank.Five, Suit.Autumn));
+
+            Assert.AreEqual(3, state.HandSizes()[0]);
+
+            new SowCards(player, new List<Card> { two }, summer).Perform(state);
+
+            Assert.AreEqual(2, state.HandSizes()[0]);
+
+            new HarvestCards(player, five, summer, new List<Pile> { summer.Piles[0] }).Perform(state);
+
+            CollectionAssert.AreEqual(new[] { 1, 0, 0, 0 }, state.HandSizes());
+        }
+
+        [TestMethod]
+        public void OrdinaryPositionHasALegalMove()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            player.Hand.Add(new Card(Rank.Two, Suit.Spring));
+
+            Assert.IsTrue(state.HasLegalMove(player));
+            Assert.IsNull(state.WhyNoMove(player));
+        }
+
+        [TestMethod]
+        public void CardNoFieldAcceptsIsReportedAsStuck()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            player.Hand.Add(new Card(Rank.Two, Suit.Spring));
+            foreach (var field in state.Fields) field.Season = Season.Autumn;
+
+            Assert.IsFalse(state.HasLegalMove(player));
+            StringAssert.Contains(state.WhyNoMove(player), "can't sow, harvest or stockpile");
+        }
+
+        [TestMethod]
+        public void EmptyHandsAtTheEndOfARoundHaveNoMove()
+        {
+            var game = TestGames.New();
+            Playout.RandomRoundPlayout(game, new System.Random(3));
+            var state = game.State!;
+
+            foreach (var player in state.Players)
+            {
+                Assert.IsFalse(state.HasLegalMove(player));
+                StringAssert.Contains(state.WhyNoMove(player), "no cards in hand");
+            }
+        }
+
+        [TestMethod]
+        public void SowingNothingIsRejected()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            player.Hand.Add(new Card(Rank.Two, Suit.Spring));
+
+            var pass = new SowCards(player, new List<Card>(), state.Fields[1]);
+
+            var error = Assert.ThrowsException<IllimatException>(() => pass.Perform(state));
+            Assert.AreEqual(IllimatError.MustPlayCard, error.Error);
+        }
+
+        [TestMethod]
+        public void HypotheticalActionsShowWhatAnAssumedCardCouldDo()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var opponent = state.Players[1];
+            var two = TestGames.LoosePile(Rank.Two, Suit.Spring);
+            var three = TestGames.LoosePile(Rank.Three, Suit.Autumn);
+            state.Fields[1].Piles.AddRange(new[] { two, three });
+            state.Fields[3].Piles.Add(TestGames.LoosePile(Rank.Five, Suit.Winter));
+
+            var actions = state.HypotheticalActions(opponent, new Card(Rank.Five, Suit.Stars));
+
+            Assert.AreEqual(3, actions.OfType<SowCards>().Count());
+            var harvest = actions.OfType<HarvestCards>().Single();
+            Assert.AreSame(state.Fields[1], harvest.Field);
+            CollectionAssert.AreEquivalent(new[] { two, three }, harvest.Piles);
+        }
+
+        [TestMethod]
+        public void HypotheticalActionsRejectVisibleCards()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            state.Fields[1].Piles.Add(TestGames.LoosePile(Rank.Five, Suit.Stars));
+
+            Assert.ThrowsException<System.ArgumentException>(() =>
+                state.HypotheticalActions(state.Players[1], new Card(Rank.Five, Suit.Stars)));
+        }
+
+        [TestMethod]
+        public void LegalActionsCoverEveryMoveInAHandBuiltPosition()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            player.Hand.AddRange(new[] { new Card(Rank.Five, Suit.Summer), new Card(Rank.Two, Suit.Spring) });
+            state.Fields[0].Piles.Add(TestGames.LoosePile(Rank.Three, Suit.Autumn));
+            state.Fields[1].Piles.Add(TestGames.LoosePile(Rank.Five, Suit.Winter));
+
+            var actions = state.LegalActions(player);
+
+            Assert.AreEqual(6, actions.OfType<SowCards>().Count());
+            Assert.AreEqual(1, actions.OfType<HarvestCards>().Count());
+            Assert.AreEqual(0, actions.OfType<StockpileCards>().Count());
+
+            foreach (var action in actions)
+            {
+                action.Perform(state);
+                action.Unwind(state);
+            }
+        }
+
+        [TestMethod]
+        public void LegalActionsIncludeStockpilesAndUnionHarvests()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            summer.Luminary = new Luminary(LuminaryName.TheUnion) { IsRevealed = true };
+            player.Hand.AddRange(new[] { new Card(Rank.Two, Suit.Spring), new Card(Rank.Three, Suit.Winter), new Card(Rank.Seven, Suit.Summer) });
+            summer.Piles.Add(TestGames.LoosePile(Rank.Five, Suit.Autumn));
+
+            var actions = state.LegalActions(player);
+
+            Assert.AreEqual(1, actions.OfType<HarvestCards>().Count(x => x.Cards.Count == 2));
+            Assert.AreEqual(1, actions.OfType<StockpileCards>().Count());
+
+            foreach (var action in actions)
+            {
+                action.Perform(state);
+                action.Unwind(state);
+            }
+        }
+
+        [TestMethod]
+        public void EveryFieldFacesTheFieldTwoAcross()
+        {
+            var state = new Game(seed: 1).State!;
+
+            Assert.AreSame(state.Fields[2], state.OppositeField(state.Fields[0]));
+            Assert.AreSame(state.Fields[3], state.OppositeField(state.Fields[1]));
+            Assert.AreSame(state.Fields[0], state.OppositeField(state.Fields[2]));
+            Assert.AreSame(state.Fields[1], state.OppositeField(state.Fields[3]));
+        }
+
+        [TestMethod]
+        public void OppositeFieldRejectsAFieldNotInPlay()
+        {
+            var state = new Game(seed: 1).State!;
+
+            Assert.ThrowsException<System.ArgumentException>(() => state.OppositeField(new Field(Season.Spring)));
+        }
+
+        [TestMethod]
+        public void CardsAreConservedThroughPlayAndUndo()
+        {
+            var game = TestGames.Dealt();
+            var state = game.State!;
+            var player = state.Players[state.ActivePlayerIndex];
+
+            game.Perform(state.LegalActions(player).First());
+            state.AssertCardConservation();
+
+            game.UndoLast();
+            state.AssertCardConservation();
+            Assert.AreEqual(65, state.AllCardsInPlay().Count());
+        }
+
+        [TestMethod]
+        public void LostOrDuplicatedCardsBreakConservation()
+        {
+            var state = TestGames.Dealt().State!;
+            var player = state.Players[0];
+
+            player.Hand.Add(player.Hand[0]);
+            Assert.ThrowsException<System.InvalidOperationException>(() => state.AssertCardConservation());
+
+            player.Hand.RemoveAt(player.Hand.Count - 1);
+            state.CardDeck.Cards.RemoveAt(0);
+            Assert.ThrowsException<System.InvalidOperationException>(() => state.AssertCardConservation());
+        }
+
+        [TestMethod]
+        public void AccessorsFindPlayersAndFieldsByIndex()
+        {
+            var state = TestGames.Dealt().State!;
+            var active = state.Players[state.ActivePlayerIndex];
+
+            Assert.AreSame(active, state.ActivePlayer);
+            CollectionAssert.AreEqual(active.Hand, state.CurrentHand.ToList());
+            Assert.AreSame(state.Players[2], state.PlayerAt(2));
+            CollectionAssert.AreEqual(state.Players[1].Hand, state.HandOf(1)!.ToList());
+            CollectionAssert.AreEqual(state.Players[1].HarvestPile.ToList(), state.HarvestOf(1)!.ToList());
+            Assert.AreSame(state.Fields[3], state.FieldAt(3));
+            Assert.AreSame(state.Fields[1], state.FieldIn(Season.Summer));
+        }
+
+        [TestMethod]
+        public void AccessorsReturnNullOutOfRange()
+        {
+            var state = TestGames.Dealt().State!;
+
+            Assert.IsNull(state.PlayerAt(-1));
+            Assert.IsNull(state.PlayerAt(state.Players.Count));
+            Assert.IsNull(state.HandOf(state.Players.Count));
+            Assert.IsNull(state.HarvestOf(-1));
+            Assert.IsNull(state.FieldAt(4));
+        }
+
+        [TestMethod]
+        public void HarvestsAndClearingFollowTheSuitRule()
+        {
+            var game = new Game(seed: 1, config: new GameConfig { HarvestSuitRule = HarvestSuitRule.MatchSuit });
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            summer.Piles.AddRange(new[] { TestGames.LoosePile(Rank.Two, Suit.Spring), TestGames.LoosePile(Rank.Three, Suit.Spring), TestGames.LoosePile(Rank.Five, Suit.Autumn) });
+            player.Hand.Add(new Card(Rank.Five, Suit.Spring));
+
+            var harvest = state.LegalActions(player).OfType<HarvestCards>().Single();
+
+            Assert.AreEqual(2, harvest.Piles.Count);
+            Assert.IsTrue(harvest.Piles.SelectMany(x => x.Cards).All(x => x.Suit == Suit.Spring));
+            Assert.IsFalse(state.CanClearField(player, summer));
+
+            summer.Piles.RemoveAt(2);
+
+            Assert.IsTrue(state.CanClearField(player, summer));
+        }
+
+        [TestMethod]
+        public void LegalActionsCarryABoatHarvestAcross()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var winter = state.OppositeField(summer);
+            var far = TestGames.LoosePile(Rank.Six, Suit.Stars);
+            summer.Luminary = new Luminary(LuminaryName.TheBoat) { IsRevealed = true };
+            summer.Piles.Add(TestGames.LoosePile(Rank.Six, Suit.Autumn));
+            winter.Piles.Add(far);
+            player.Hand.Add(new Card(Rank.Six, Suit.Spring));
+
+            var harvests = state.LegalActions(player).OfType<HarvestCards>().ToList();
+
+            Assert.AreEqual(2, harvests.Count);
+            Assert.AreSame(far, harvests.Single(x => x.OppositePiles.Count > 0).OppositePiles.Single());
+            Assert.IsFalse(state.CanHarvest(winter));
+            Assert.IsTrue(state.CanClearField(player, winter));
+
+            foreach (var harvest in harvests)
+            {
+                harvest.Perform(state);
+                harvest.Unwind(state);
+            }
+        }
+
+        // The Union is dealt to Spring's field and the Boat to Summer's, so pairs and harvests across both turn up.
+        private static readonly LuminaryName[] BoatAndFriends =
+            { LuminaryName.TheUnion, LuminaryName.TheBoat, LuminaryName.TheRake, LuminaryName.TheChangeling };
+
+        private static readonly GameConfig[] Configs =
+        {
+            new GameConfig(),
+            new GameConfig { HarvestSuitRule = HarvestSuitRule.MatchSuit },
+            new GameConfig { LuminaryRevealMode = LuminaryRevealMode.AtDeal },
+            new GameConfig { UseLuminaries = false },
+            new GameConfig { LuminaryRevealMode = LuminaryRevealMode.AtDeal, Luminaries = BoatAndFriends },
+            new GameConfig { LuminaryRevealMode = LuminaryRevealMode.AtDeal, Luminaries = BoatAndFriends, HarvestSuitRule = HarvestSuitRule.MatchSuit }
+        };
+
+        [TestMethod]
+        public void EveryLegalActionCanBePerformedUnderEachConfig()
+        {
+            foreach (var config in Configs)
+            {
+                for (int seed = 1; seed <= 4; seed++)
+                {
+                    var game = TestGames.Dealt(seed, config);
+                    var state = game.State!;
+                    var random = new System.Random(seed);
+
+                    for (int turn = 0; turn < 16; turn++)
+                    {
+                        var player = state.ActivePlayer;
+
+                        foreach (var action in state.LegalActions(player))
+                        {
+                            game.Perform(action);
+                            game.UndoLast();
+                        }
+
+                        var actions = state.LegalActions(player);
+                        if (actions.Count == 0) break;
+
+                        game.Perform(actions[random.Next(actions.Count)]);
+                        if (!game.AdvanceTurn()) break;
+                    }
+                }
+            }
+        }
+
+        [TestMethod]
+        public void CanClearFieldAgreesWithTheLegalHarvests()
+        {
+            foreach (var config in Configs)
+            {
+                for (int seed = 1; seed <= 4; seed++)
+                {
+                    var game = TestGames.Dealt(seed, config);
+                    var state = game.State!;
+                    var random = new System.Random(seed);
+
+                    for (int turn = 0; turn < 16; turn++)
+                    {
+                        var player = state.ActivePlayer;
+                        var actions = state.LegalActions(player);
+                        var harvests = actions.OfType<HarvestCards>().ToList();
+
+                        foreach (var field in state.Fields)
+                        {
+                            var opposite = state.OppositeField(field);
+                            var clears = field.Piles.Count > 0 && harvests.Any(x =>
+                                (x.Field == field && x.Piles.Count == field.Piles.Count) ||
+                                (x.Field == opposite && x.OppositePiles.Count == field.Piles.Count));
+
+                            Assert.AreEqual(clears, state.CanClearField(player, field),
+                                $"Field {field} on turn {state.TurnNumber} of seed {seed}.");
+                        }
+
+                        if (actions.Count == 0) break;
+
+                        game.Perform(actions[random.Next(actions.Count)]);
+                        if (!game.AdvanceTurn()) break;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,304 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class GameTests
+    {
+        [TestMethod]
+        public void PerformForRejectsActionsOutOfTurn()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var waitingPlayer = state.Players[(state.ActivePlayerIndex + 1) % state.Players.Count];
+
+            var error = Assert.ThrowsException<IllimatException>(() => game.PerformFor(waitingPlayer, new DrawUp(waitingPlayer)));
+            Assert.AreEqual(IllimatError.NotYourTurn, error.Error);
+            Assert.AreEqual(0, waitingPlayer.Hand.Count);
+            Assert.AreEqual(0, game.CompletedActions.Count);
+        }
+
+        [TestMethod]
+        public void PerformForAcceptsTheActivePlayersAction()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var activePlayer = state.Players[state.ActivePlayerIndex];
+
+            game.PerformFor(activePlayer, new DrawUp(activePlayer));
+
+            Assert.AreEqual(4, activePlayer.Hand.Count);
+            Assert.AreEqual(1, game.CompletedActions.Count);
+        }
+
+        [TestMethod]
+        public void RepetitionCountTracksAnExchangeCycle()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var two = new Card(Rank.Two, Suit.Spring, true);
+            var three = new Card(Rank.Three, Suit.Autumn, true);
+            player.Hand.Add(two);
+            summer.Piles.Add(new Pile(new List<Card> { three }));
+            state.ChangelingClaimExchanges = (player, 4, state.TurnNumber);
+
+            game.Perform(new ChangelingClaimExchange(player, new[] { new ExchangeCard(player, summer, two, three) }));
+            Assert.AreEqual(1, game.RepetitionCount());
+
+            game.Perform(new ChangelingClaimExchange(player, new[] { new ExchangeCard(player, summer, three, two) }));
+            game.Perform(new ChangelingClaimExchange(player, new[] { new ExchangeCard(player, summer, two, three) }));
+            Assert.AreEqual(2, game.RepetitionCount());
+
+            game.Perform(new ChangelingClaimExchange(player, new[] { new ExchangeCard(player, summer, three, two) }));
+            Assert.AreEqual(2, game.RepetitionCount());
+        }
+
+        [TestMethod]
+        public void ScoringTheRoundResetsRepetitions()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var two = new Card(Rank.Two, Suit.Spring, true);
+            var three = new Card(Rank.Three, Suit.Autumn, true);
+            player.Hand.Add(two);
+            summer.Piles.Add(new Pile(new List<Card> { three }));
+            state.ChangelingClaimExchanges = (player, 1, state.TurnNumber);
+            game.Perform(new ChangelingClaimExchange(player, new[] { new ExchangeCard(player, summer, two, three) }));
+
+            game.Perform(new ScoreRound(game));
+
+            Assert.AreEqual(0, game.RepetitionCount());
+        }
+
+        [TestMethod]
+        public void UndoingTheScoreBringsTheRepetitionsBack()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var two = new Card(Rank.Two, Suit.Spring, true);
+            var three = new Card(Rank.Three, Suit.Autumn, true);
+            player.Hand.Add(two);
+            summer.Piles.Add(new Pile(new List<Card> { three }));
+            state.ChangelingClaimExchanges = (player, 1, state.TurnNumber);
+            game.Perform(new ChangelingClaimExchange(player, new[] { new ExchangeCard(player, summer, two, three) }));
+            game.Perform(new ScoreRound(game));
+
+            game.UndoLast();
+
+            Assert.AreEqual(1, game.RepetitionCount());
+        }
+
+        [TestMethod]
+        public void UndoingAPendingActionQueuesItToBePerformedNext()
+        {
+            var game = TestGames.New();
+            var state = game.State!;
+            var pendingCount = game.PendingActions.Count;
+            var seedSpring = game.PerformPending();
+            var springPiles = state.Fields[0].Piles.SelectMany(x => x.Cards).ToList();
+
+            game.UndoLast();
+
+            Assert.AreEqual(pendingCount, game.PendingActions.Count);
+            Assert.AreSame(seedSpring, game.PendingActions.Peek());
+            Assert.AreEqual(0, state.Fields[0].Piles.Count);
+            Assert.AreEqual(0, game.ReplayMoves.Count);
+
+            Assert.AreSame(seedSpring, game.PerformPending());
+            CollectionAssert.AreEqual(springPiles, state.Fields[0].Piles.SelectMany(x => x.Cards).ToList());
+        }
+
+        [TestMethod]
+        public void RestartTurnUndoesHarvestAndOkusCollection()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var five = new Card(Rank.Five, Suit.Summer);
+            var target = new Pile(new List<Card> { new Card(Rank.Five, Suit.Autumn, true) });
+            var okus = new Okus(player, "A");
+            summer.Piles.Add(target);
+            player.Hand.Add(five);
+            state.IllimatOkuses.Add(okus);
+            game.Perform(new BeginTurn(player));
+            var startKey = state.BoardKey();
+            var startDeckCount = state.CardDeck.Cards.Count;
+
+            game.Perform(new HarvestCards(player, five, summer, new List<Pile> { target }));
+            game.Perform(new CollectOkus(player, okus));
+            game.RestartTurn();
+
+            Assert.AreEqual(startKey, state.BoardKey());
+            Assert.AreEqual(startDeckCount, state.CardDeck.Cards.Count);
+            CollectionAssert.AreEqual(new[] { okus }, state.IllimatOkuses);
+            Assert.AreEqual(0, player.ScorePileOkuses.Count);
+            Assert.IsInstanceOfType(game.CompletedActions.Peek(), typeof(BeginTurn));
+        }
+
+        [TestMethod]
+        public void RestartTurnNeedsATurnToHaveBegun()
+        {
+            var game = new Game(seed: 1);
+
+            Assert.ThrowsException<InvalidOperationException>(() => game.RestartTurn());
+        }
+
+        [TestMethod]
+        public void UnseededGameCanBeRebuiltFromItsSeed()
+        {
+            var original = TestGames.Deal(new Game());
+            var rebuilt = TestGames.Dealt(original.Seed);
+
+            Assert.IsTrue(original.State!.Players.All(x => x.Hand.Count > 0));
+            CollectionAssert.AreEqual(original.State.CardDeck.Cards.ToList(), rebuilt.State!.CardDeck.Cards.ToList());
+            for (int i = 0; i < original.State.Players.Count; i++)
+            {
+                Assert.AreEqual(original.State.Players[i].Name, rebuilt.State.Players[i].Name);
+                CollectionAssert.AreEqual(original.State.Players[i].Hand, rebuilt.State.Players[i].Hand);
+            }
+            for (int i = 0; i < original.State.Fields.Length; i++)
+            {
+                CollectionAssert.AreEqual(
+                    original.State.Fields[i].Piles.SelectMany(x => x.Cards).ToList(),
+                    rebuilt.State.Fields[i].Piles.SelectMany(x => x.Cards).ToList());
+            }
+        }
+
+        private static string Snapshot(GameState state) => string.Join("|",
+            state.BoardKey(),
+            state.TurnNumber,
+            string.Join(",", state.CardDeck.Cards.Select(x => $"{x.Rank}{x.Suit}")),
+            string.Join(",", state.Fields.Select(x => $"{x.Luminary?.LuminaryName}:{x.Luminary?.IsRevealed}:{x.HiddenCards.Count}")),
+            state.IllimatOkuses.Count,
+            string.Join(",", state.Players.Select(x =>
+                $"{string.Join("+", x.HarvestPile.Select(card => $"{card.Rank}{card.Suit}"))}/{x.ScorePileOkuses.Count}/" +
+                $"{x.ScorePileLuminaries.Count}/{x.ActionStats.Sows}/{x.ActionStats.Harvests}/{x.ActionStats.Stockpiles}")));
+
+        [TestMethod]
+        public void UndoLastRestoresThePositionBeforeEachAction()
+        {
+            var game = TestGames.Dealt();
+            var state = game.State!;
+            var player = state.Players[state.ActivePlayerIndex];
+            var next = state.Players[(state.ActivePlayerIndex + 1) % state.Players.Count];
+            var hiddenLuminaryField = state.Fields.First(x => x.Luminary is { IsRevealed: false });
+            var exchangeField = state.Fields.First(x => state.ExchangeCandidates(x).Any());
+            state.PerfectCrimeTheft = (player, 1, state.TurnNumber);
+            state.ChangelingClaimExchanges = (player, 2, state.TurnNumber);
+
+            var actions = state.LegalActions(player).Concat(new IAction[]
+            {
+                new DrawUp(player),
+                new BeginTurn(next),
+                new RevealLuminary(game, hiddenLuminaryField),
+                new CollectLuminary(player, hiddenLuminaryField),
+                new StealOkus(player, null),
+                new ChangelingClaimExchange(player, new[]
+                {
+                    new ExchangeCard(player, exchangeField, player.Hand[0], state.ExchangeCandidates(exchangeField).First())
+                })
+            });
+
+            foreach (var action in actions)
+            {
+                var before = Snapshot(state);
+
+                game.Perform(action);
+                game.UndoLast();
+
+                Assert.AreEqual(before, Snapshot(state), action.ToString());
+            }
+        }
+
+        [TestMethod]
+        public void UndoLastNeedsAnAction()
+        {
+            var game = new Game(seed: 1);
+
+            Assert.ThrowsException<InvalidOperationException>(() => game.UndoLast());
+        }
+
+        [TestMethod]
+        public void AdvanceTurnPassesPlayToTheLeft()
+        {
+            var game = TestGames.Dealt();
+            var state = game.State!;
+            var first = state.ActivePlayerIndex;
+
+            for (int i = 1; i <= state.Players.Count; i++)
+            {
+                Assert.IsTrue(game.AdvanceTurn());
+                Assert.AreEqual((first + i) % state.Players.Count, state.ActivePlayerIndex);
+            }
+        }
+
+        [TestMethod]
+        public void AdvanceTurnRefillsTheHandThatJustPlayed()
+        {
+            var game = TestGames.Dealt();
+            var state = game.State!;
+            var player = state.ActivePlayer;
+            game.Perform(state.LegalActions(player).OfType<SowCards>().First());
+            var deckCount = state.CardDeck.Cards.Count;
+            var missing = 4 - player.Hand.Count;
+            var turnNumber = state.TurnNumber;
+
+            game.AdvanceTurn();
+
+            Assert.AreEqual(4, player.Hand.Count);
+            Assert.AreEqual(deckCount - missing, state.CardDeck.Cards.Count);
+            Assert.AreEqual(turnNumber + 1, state.TurnNumber);
+        }
+
+        [TestMethod]
+        public void AdvanceTurnFreesTheLoomForTheNextTurn()
+        {
+            var game = TestGames.Dealt();
+            var state = game.State!;
+            var spring = state.FieldIn(Season.Spring);
+            state.Players.ForEach(x => x.ScorePileLuminaries.Add(new Luminary(LuminaryName.TheLoom) { IsRevealed = true }));
+            state.LoomStockpileTurn = state.TurnNumber;
+            Assert.IsFalse(state.CanStockpile(spring, state.ActivePlayer));
+
+            game.AdvanceTurn();
+
+            Assert.IsTrue(state.CanStockpile(spring, state.ActivePlayer));
+        }
+
+        [TestMethod]
+        public void AdvanceTurnQueuesScoringWhenNoCardsRemain()
+        {
+            var game = TestGames.Dealt();
+            var state = game.State!;
+            state.CardDeck.Cards.Clear();
+            state.Players.ForEach(x => x.Hand.Clear());
+
+            Assert.IsFalse(game.AdvanceTurn());
+            Assert.IsInstanceOfType(game.PendingActions.Last(), typeof(ScoreRound));
+        }
+
+        [TestMethod]
+        public void OnlyQueuedActionsMayBePerformedWithoutNotation()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var queued = game.PendingActions.Peek();
+
+            Assert.ThrowsException<ArgumentException>(() => game.Perform(new SeedField(game, state.Fields[0])));
+            Assert.AreSame(queued, game.PerformPending());
+            Assert.AreEqual(GameReplay.PENDING_MOVE, game.ReplayMoves.Single());
+        }
+    }
+}
@@ -0,0 +1,212 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class HarvestCardsTests
+    {
+        [TestMethod]
+        public void HarvestMovesCardsToHarvestPileAndClearsField()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var five = new Card(Rank.Five, Suit.Summer);
+            var two = TestGames.LoosePile(Rank.Two, Suit.Spring);
+            var three = TestGames.LoosePile(Rank.Three, Suit.Autumn);
+            summer.Piles.AddRange(new[] { two, three });
+            player.Hand.Add(five);
+
+            var harvest = new HarvestCards(player, five, summer, new List<Pile> { two, three });
+            harvest.Perform(state);
+
+            Assert.IsTrue(harvest.FieldCleared);
+            Assert.AreEqual(3, player.HarvestPile.Count);
+            CollectionAssert.DoesNotContain(player.Hand, five);
+
+            harvest.Unwind(state);
+
+            Assert.AreEqual(0, player.HarvestPile.Count);
+            Assert.AreEqual(2, summer.Piles.Count);
+            CollectionAssert.Contains(player.Hand, five);
+        }
+
+        [TestMethod]
+        public void HarvestRejectsPilesThatDontAddUp()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var five = new Card(Rank.Five, Suit.Summer);
+            var seven = TestGames.LoosePile(Rank.Seven, Suit.Spring);
+            summer.Piles.Add(seven);
+            player.Hand.Add(five);
+
+            var harvest = new HarvestCards(player, five, summer, new List<Pile> { seven });
+
+            var error = Assert.ThrowsException<IllimatException>(() => harvest.Perform(state));
+            Assert.AreEqual(IllimatError.NoMatchingHarvest, error.Error);
+        }
+
+        [TestMethod]
+        public void SowingIsBlockedInAutumn()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var two = new Card(Rank.Two, Suit.Spring);
+            player.Hand.Add(two);
+
+            var sow = new SowCards(player, new List<Card> { two }, state.Fields[2]);
+
+            var error = Assert.ThrowsException<IllimatException>(() => sow.Perform(state));
+            Assert.AreEqual(IllimatError.SeasonBlocked, error.Error);
+        }
+
+        [TestMethod]
+        public void MatchSuitRuleRejectsCrossSuitHarvest()
+        {
+            var game = new Game(seed: 1, config: new GameConfig { HarvestSuitRule = HarvestSuitRule.MatchSuit });
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var five = new Card(Rank.Five, Suit.Summer);
+            var crossSuit = TestGames.LoosePile(Rank.Five, Suit.Autumn);
+            var sameSuit = TestGames.LoosePile(Rank.Five, Suit.Summer);
+            summer.Piles.AddRange(new[] { crossSuit, sameSuit });
+            player.Hand.Add(five);
+
+            var error = Assert.ThrowsException<IllimatException>(() =>
+                new HarvestCards(player, five, summer, new List<Pile> { crossSuit }).Perform(state));
+            Assert.AreEqual(IllimatError.SuitMismatch, error.Error);
+
+            new HarvestCards(player, five, summer, new List<Pile> { sameSuit }).Perform(state);
+
+            Assert.AreEqual(2, player.HarvestPile.Count);
+        }
+
+        [TestMethod]
+        public void AnySuitRuleAllowsCrossSuitHarvest()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var five = new Card(Rank.Five, Suit.Summer);
+            var six = new Card(Rank.Six, Suit.Summer);
+            var crossSuit = TestGames.LoosePile(Rank.Five, Suit.Autumn);
+            var sameSuit = TestGames.LoosePile(Rank.Six, Suit.Summer);
+            summer.Piles.AddRange(new[] { crossSuit, sameSuit });
+            player.Hand.AddRange(new[] { five, six });
+
+            new HarvestCards(player, five, summer, new List<Pile> { crossSuit }).Perform(state);
+            new HarvestCards(player, six, summer, new List<Pile> { sameSuit }).Perform(state);
+
+            Assert.AreEqual(4, player.HarvestPile.Count);
+        }
+
+        [TestMethod]
+        public void ClearingFieldAwardsHiddenCardsInOrder()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            summer.Luminary = new Luminary(LuminaryName.TheSoldiers) { IsRevealed = true };
+            var firstHidden = new Card(Rank.Nine, Suit.Winter);
+            var secondHidden = new Card(Rank.Four, Suit.Stars);
+            summer.HiddenCards.AddRange(new[] { firstHidden, secondHidden });
+            var five = new Card(Rank.Five, Suit.Summer);
+            var target = TestGames.LoosePile(Rank.Five, Suit.Autumn);
+            summer.Piles.Add(target);
+            player.Hand.Add(five);
+            var events = new List<GameEvent>();
+            game.EventRaised += events.Add;
+
+            var harvest = new HarvestCards(player, five, summer, new List<Pile> { target });
+            harvest.Perform(state);
+
+            CollectionAssert.AreEqual(new[] { firstHidden, secondHidden }, player.HarvestPile.Skip(2).ToArray());
+            Assert.IsTrue(firstHidden.IsRevealed && secondHidden.IsRevealed);
+            Assert.AreEqual(0, summer.HiddenCards.Count);
+            Assert.AreEqual(GameEventType.HiddenCardsRevealed, events.Last().Type);
+
+            harvest.Unwind(state);
+
+            Assert.AreEqual(0, player.HarvestPile.Count);
+            CollectionAssert.AreEqual(new[] { firstHidden, secondHidden }, summer.HiddenCards);
+        }
+
+        [TestMethod]
+        public void ClearingAFieldRevealsTheRiverAndDealsSixCards()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            summer.Luminary = new Luminary(LuminaryName.TheRiver);
+            var five = new Card(Rank.Five, Suit.Summer);
+            var target = TestGames.LoosePile(Rank.Five, Suit.Spring);
+            summer.Piles.Add(target);
+            player.Hand.Add(five);
+            var deckCount = state.CardDeck.Cards.Count;
+
+            var harvest = new HarvestCards(player, five, summer, new List<Pile> { target });
+            harvest.Perform(state);
+
+            Assert.IsTrue(harvest.FieldCleared);
+            Assert.IsTrue(summer.Luminary!.IsRevealed);
+            Assert.AreEqual(6, summer.Piles.Count);
+            Assert.IsTrue(summer.Piles.All(x => x.Cards.Single().IsRevealed));
+            Assert.AreEqual(deckCount - 6, state.CardDeck.Cards.Count);
+
+            harvest.Unwind(state);
+
+            Assert.IsFalse(summer.Luminary!.IsRevealed);
+            CollectionAssert.AreEqual(new[] { target }, summer.Piles);
+            Assert.AreEqual(deckCount, state.CardDeck.Cards.Count);
+        }
+
+        [TestMethod]
+        public void ClearingARevealedLuminarysFieldAgainClaimsIt()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var rake = new Luminary(LuminaryName.TheRake);
+            summer.Luminary = rake;
+            var five = new Card(Rank.Five, Suit.Summer);
+            var seven = new Card(Rank.Seven, Suit.Summer);
+            var firstTarget = TestGames.LoosePile(Rank.Five, Suit.Spring);
+            var secondTarget = TestGames.LoosePile(Rank.Seven, Suit.Spring);
+            summer.Piles.Add(firstTarget);
+            player.Hand.AddRange(new[] { five, seven });
+
+            new HarvestCards(player, five, summer, new List<Pile> { firstTarget }).Perform(state);
+
+            Assert.IsTrue(rake.IsRevealed);
+            Assert.AreSame(rake, summer.Luminary);
+
+            summer.Piles.Add(secondTarget);
+            var claim = new HarvestCards(player, seven, summer, new List<Pile> { secondTarget });
+            claim.Perform(state);
+
+            Assert.IsNull(summer.Luminary);
+            CollectionAssert.AreEqual(new[] { rake }, player.ScorePileLuminaries);
+
+            claim.Unwind(state);
+
+            Assert.AreSame(rake, summer.Luminary);
+            Assert.IsTrue(rake.IsRevealed);
+            Assert.AreEqual(0, player.ScorePileLuminaries.Count);
+        }
+    }
+}
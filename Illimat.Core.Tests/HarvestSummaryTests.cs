@@ -0,0 +1,88 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class HarvestSummaryTests
+    {
+        private static void AssertMatchesRecount(Player player)
+        {
+            var recount = HarvestSummary.FromCards(player.HarvestPile);
+
+            Assert.AreEqual(recount.CardCount, player.HarvestSummary.CardCount);
+            Assert.AreEqual(recount.FoolCount, player.HarvestSummary.FoolCount);
+            CollectionAssert.AreEquivalent(recount.SuitCounts, player.HarvestSummary.SuitCounts);
+            CollectionAssert.AreEquivalent(recount.QueenSuits.ToList(), player.HarvestSummary.QueenSuits.ToList());
+        }
+
+        [TestMethod]
+        public void SummaryTracksHarvestsUnwindsAndRoundResets()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var fool = new Card(Rank.Fool, Suit.Summer);
+            var four = new Card(Rank.Four, Suit.Stars);
+            var fivePile = new Pile(new List<Card> { new Card(Rank.Five, Suit.Summer, true) });
+            var ninePile = new Pile(new List<Card> { new Card(Rank.Nine, Suit.Spring, true) });
+            var fourPile = new Pile(new List<Card> { new Card(Rank.Four, Suit.Autumn, true) });
+            summer.Piles.AddRange(new[] { fivePile, ninePile, fourPile });
+            player.Hand.AddRange(new[] { fool, four });
+
+            new HarvestCards(player, fool, summer, new List<Pile> { fivePile, ninePile }).Perform(state);
+            AssertMatchesRecount(player);
+            Assert.AreEqual(1, player.HarvestSummary.FoolCount);
+            Assert.AreEqual(2, player.HarvestSummary.SuitCounts[Suit.Summer]);
+
+            var harvest = new HarvestCards(player, four, summer, new List<Pile> { fourPile });
+            harvest.Perform(state);
+            AssertMatchesRecount(player);
+            Assert.AreEqual(5, player.HarvestSummary.CardCount);
+
+            harvest.Unwind(state);
+            AssertMatchesRecount(player);
+            Assert.AreEqual(3, player.HarvestSummary.CardCount);
+
+            player.ClearHarvest();
+            AssertMatchesRecount(player);
+            Assert.AreEqual(0, player.HarvestSummary.CardCount);
+        }
+
+        [TestMethod]
+        public void QueenFlagsFollowTheQueensInTheHarvest()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var queen = new Card(Rank.Queen, Suit.Summer);
+            var sevenPile = new Pile(new List<Card> { new Card(Rank.Seven, Suit.Winter, true) });
+            var fivePile = new Pile(new List<Card> { new Card(Rank.Five, Suit.Autumn, true) });
+            var springQueenPile = new Pile(new List<Card> { new Card(Rank.Queen, Suit.Spring, true) });
+            summer.Piles.AddRange(new[] { sevenPile, fivePile, springQueenPile });
+            player.Hand.Add(queen);
+
+            var harvest = new HarvestCards(player, queen, summer, new List<Pile> { sevenPile, fivePile, springQueenPile });
+            harvest.Perform(state);
+            AssertMatchesRecount(player);
+            Assert.IsTrue(player.HarvestSummary.HasQueen(Suit.Summer));
+            Assert.IsTrue(player.HarvestSummary.HasQueen(Suit.Spring));
+            Assert.IsFalse(player.HarvestSummary.HasQueen(Suit.Winter));
+
+            harvest.Unwind(state);
+            AssertMatchesRecount(player);
+            Assert.AreEqual(0, player.HarvestSummary.QueenSuits.Count);
+
+            player.AddToHarvest(new[] { new Card(Rank.Queen, Suit.Stars, true) });
+            Assert.IsTrue(player.HarvestSummary.HasQueen(Suit.Stars));
+            player.ClearHarvest();
+            AssertMatchesRecount(player);
+            Assert.IsFalse(player.HarvestSummary.HasQueen(Suit.Stars));
+        }
+    }
+}
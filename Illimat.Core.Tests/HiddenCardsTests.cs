@@ -0,0 +1,112 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class HiddenCardsTests
+    {
+        [TestMethod]
+        public void RevealingTheChildrenDealsThreeHiddenCards()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var field = state.Fields[2];
+            field.Luminary = new Luminary(LuminaryName.TheChildren);
+            var deckCount = state.CardDeck.Cards.Count;
+            var reveal = new RevealLuminary(game, field);
+
+            reveal.Perform(state);
+
+            Assert.IsTrue(field.Luminary!.IsRevealed);
+            Assert.AreEqual(3, field.HiddenCards.Count);
+            Assert.AreEqual(deckCount - 3, state.CardDeck.Cards.Count);
+
+            reveal.Unwind(state);
+
+            Assert.IsFalse(field.Luminary!.IsRevealed);
+            Assert.AreEqual(0, field.HiddenCards.Count);
+            Assert.AreEqual(deckCount, state.CardDeck.Cards.Count);
+        }
+
+        [TestMethod]
+        public void ClaimingTheSoldiersTransfersHiddenCardsToHarvest()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var field = state.Fields[2];
+            field.Luminary = new Luminary(LuminaryName.TheSoldiers);
+            new RevealLuminary(game, field).Perform(state);
+            var hidden = field.HiddenCards.ToList();
+
+            new CollectLuminary(player, field).Perform(state);
+
+            Assert.IsNull(field.Luminary);
+            Assert.AreEqual(LuminaryName.TheSoldiers, player.ScorePileLuminaries.Single().LuminaryName);
+            CollectionAssert.AreEqual(hidden, player.HarvestPile.ToList());
+            Assert.IsTrue(hidden.All(x => x.IsRevealed));
+        }
+
+        [TestMethod]
+        public void RevealingOtherLuminariesDealsNothing()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var field = state.Fields[2];
+            field.Luminary = new Luminary(LuminaryName.TheRake);
+            var deckCount = state.CardDeck.Cards.Count;
+
+            new RevealLuminary(game, field).Perform(state);
+
+            Assert.AreEqual(0, field.HiddenCards.Count);
+            Assert.AreEqual(deckCount, state.CardDeck.Cards.Count);
+        }
+
+        [TestMethod]
+        public void SowingIntoTheSoldiersFieldAddsAHiddenCard()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var field = state.Fields[1];
+            field.Luminary = new Luminary(LuminaryName.TheSoldiers) { IsRevealed = true };
+            var two = new Card(Rank.Two, Suit.Spring);
+            player.Hand.Add(two);
+            var deckCount = state.CardDeck.Cards.Count;
+            var sow = new SowCards(player, new List<Card> { two }, field);
+
+            sow.Perform(state);
+
+            Assert.AreEqual(2, field.Piles.Count + field.HiddenCards.Count);
+            Assert.AreEqual(deckCount - 1, state.CardDeck.Cards.Count);
+            Assert.IsFalse(field.HiddenCards.Single().IsRevealed);
+
+            sow.Unwind(state);
+
+            Assert.AreEqual(0, field.HiddenCards.Count);
+            Assert.AreEqual(deckCount, state.CardDeck.Cards.Count);
+        }
+
+        [TestMethod]
+        public void SoldiersAddNothingWhenTheDeckIsEmpty()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var field = state.Fields[1];
+            field.Luminary = new Luminary(LuminaryName.TheSoldiers) { IsRevealed = true };
+            var two = new Card(Rank.Two, Suit.Spring);
+            player.Hand.Add(two);
+            state.CardDeck.Cards.Clear();
+
+            new SowCards(player, new List<Card> { two }, field).Perform(state);
+
+            Assert.AreEqual(1, field.Piles.Count);
+            Assert.AreEqual(0, field.HiddenCards.Count);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class IllimatErrorTests
+    {
+        [TestMethod]
+        public void SowingACardFromAnotherHandIsCardNotInHand()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var card = new Card(Rank.Two, Suit.Spring);
+            state.Players[1].Hand.Add(card);
+
+            var error = Assert.ThrowsException<IllimatException>(() =>
+                new SowCards(player, new List<Card> { card }, state.Fields[1]).Perform(state));
+
+            Assert.AreEqual(IllimatError.CardNotInHand, error.Error);
+        }
+
+        [TestMethod]
+        public void HarvestingAPileFromAnotherFieldIsInvalidField()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var five = new Card(Rank.Five, Suit.Summer);
+            var elsewhere = new Pile(new List<Card> { new Card(Rank.Five, Suit.Autumn, true) });
+            state.Fields[0].Piles.Add(elsewhere);
+            player.Hand.Add(five);
+
+            var error = Assert.ThrowsException<IllimatException>(() =>
+                new HarvestCards(player, five, state.Fields[1], new List<Pile> { elsewhere }).Perform(state));
+
+            Assert.AreEqual(IllimatError.InvalidField, error.Error);
+        }
+
+        [TestMethod]
+        public void SubmittingAnotherPlayersActionIsWrongActor()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var activePlayer = state.Players[state.ActivePlayerIndex];
+            var otherPlayer = state.Players[(state.ActivePlayerIndex + 1) % state.Players.Count];
+
+            var error = Assert.ThrowsException<IllimatException>(() =>
+                game.PerformFor(activePlayer, new DrawUp(otherPlayer)));
+
+            Assert.AreEqual(IllimatError.WrongActor, error.Error);
+        }
+
+        [TestMethod]
+        public void StealingFromYourselfIsInvalidTarget()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+
+            var error = Assert.ThrowsException<IllimatException>(() =>
+                new StealOkus(player, player).Perform(state));
+
+            Assert.AreEqual(IllimatError.InvalidTarget, error.Error);
+        }
+
+        [TestMethod]
+        public void CollectingAMissingLuminaryIsLuminaryUnavailable()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            state.Fields[1].Luminary = null;
+
+            var error = Assert.ThrowsException<IllimatException>(() =>
+                new CollectLuminary(state.Players[0], state.Fields[1]).Perform(state));
+
+            Assert.AreEqual(IllimatError.LuminaryUnavailable, error.Error);
+        }
+
+        [TestMethod]
+        public void SummaryLeadsWithTheError()
+        {
+            var exception = new IllimatException(IllimatError.SeasonBlocked, "Cards cannot be sown in Autumn.");
+
+            Assert.AreEqual("SeasonBlocked: Cards cannot be sown in Autumn.", exception.Summary);
+            StringAssert.StartsWith(exception.ToString(), typeof(IllimatException).FullName);
+        }
+    }
+}
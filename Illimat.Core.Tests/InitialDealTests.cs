@@ -0,0 +1,78 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class InitialDealTests
+    {
+        [TestMethod]
+        public void InitialDealDealsALuminaryToEveryField()
+        {
+            var game = new Game(seed: 1);
+
+            Assert.AreEqual(4, game.PendingActions.OfType<DealLuminary>().Count());
+        }
+
+        [TestMethod]
+        public void InitialDealSkipsLuminariesWhenDisabled()
+        {
+            var game = new Game(seed: 1, config: new GameConfig { UseLuminaries = false });
+            var state = game.State!;
+
+            Assert.IsFalse(game.PendingActions.OfType<DealLuminary>().Any());
+            Assert.IsTrue(state.Fields.All(x => x.Luminary == null));
+            Assert.IsFalse(state.CanHarvest(state.Fields[3]));
+        }
+
+        [TestMethod]
+        public void StartingDealerSetsDealerAndFirstPlayer()
+        {
+            var game = new Game(seed: 1, config: new GameConfig { StartingDealer = 2 });
+            var state = game.State!;
+            var deals = game.PendingActions.OfType<DealHand>().ToList();
+
+            Assert.AreEqual(2, state.Dealer);
+            Assert.AreEqual(3, state.ActivePlayerIndex);
+            Assert.AreSame(state.Players[3], deals[0].Player);
+            Assert.AreEqual(3, deals[0].Count);
+            Assert.AreSame(state.Players[2], deals.Last().Player);
+            Assert.AreSame(state.Players[3], game.PendingActions.OfType<BeginTurn>().Single().Actor);
+        }
+
+        [TestMethod]
+        public void StartingDealerMustBeAPlayer()
+        {
+            Assert.ThrowsException<System.ArgumentException>(() =>
+                new Game(seed: 1, config: new GameConfig { StartingDealer = 4 }));
+        }
+
+        [TestMethod]
+        public void SmallGamesPlayWithoutTheStars()
+        {
+            foreach (var (humans, computers) in new[] { (1, 1), (1, 2), (0, 3) })
+            {
+                var state = new Game(humans, computers, seed: 1).State!;
+                var cards = state.AllCardsInPlay().ToList();
+
+                Assert.AreEqual(52, cards.Count);
+                Assert.IsFalse(cards.Any(x => x.Suit == Suit.Stars));
+                CollectionAssert.AreEquivalent(SuitSet.NoStars.ToList(), cards.Select(x => x.Suit).Distinct().ToList());
+                state.AssertCardConservation();
+            }
+        }
+
+        [TestMethod]
+        public void FourPlayerGamesAddTheStars()
+        {
+            var state = new Game(seed: 1).State!;
+            var cards = state.AllCardsInPlay().ToList();
+
+            Assert.AreEqual(65, cards.Count);
+            Assert.AreEqual(13, cards.Count(x => x.Suit == Suit.Stars));
+            state.AssertCardConservation();
+        }
+    }
+}
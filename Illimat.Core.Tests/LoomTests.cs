@@ -0,0 +1,102 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class LoomTests
+    {
+        [TestMethod]
+        public void LoomStockpilesInSpringOncePerTurn()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var spring = state.Fields[0];
+            spring.Luminary = new Luminary(LuminaryName.TheLoom) { IsRevealed = true };
+            var two = new Card(Rank.Two, Suit.Spring);
+            var three = new Card(Rank.Three, Suit.Summer);
+            var first = TestGames.LoosePile(Rank.Four, Suit.Autumn);
+            var second = TestGames.LoosePile(Rank.Two, Suit.Winter);
+            spring.Piles.AddRange(new[] { first, second });
+            player.Hand.AddRange(new[] { two, three, new Card(Rank.Six, Suit.Winter), new Card(Rank.Five, Suit.Autumn) });
+
+            new StockpileCards(player, two, spring, new List<Pile> { first }).Perform(state);
+
+            var error = Assert.ThrowsException<IllimatException>(() =>
+                new StockpileCards(player, three, spring, new List<Pile> { second }).Perform(state));
+            Assert.AreEqual(IllimatError.AlreadyUsedThisTurn, error.Error);
+
+            state.TurnNumber++;
+            new StockpileCards(player, three, spring, new List<Pile> { second }).Perform(state);
+
+            Assert.AreEqual(2, spring.Piles.Count(x => x.IsStockpile));
+        }
+
+        [TestMethod]
+        public void UnwindingALoomStockpileFreesTheLoom()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var spring = state.Fields[0];
+            spring.Luminary = new Luminary(LuminaryName.TheLoom) { IsRevealed = true };
+            var two = new Card(Rank.Two, Suit.Spring);
+            spring.Piles.Add(TestGames.LoosePile(Rank.Four, Suit.Autumn));
+            player.Hand.AddRange(new[] { two, new Card(Rank.Six, Suit.Winter) });
+            var stockpile = new StockpileCards(player, two, spring, new List<Pile> { spring.Piles[0] });
+
+            stockpile.Perform(state);
+            stockpile.Unwind(state);
+
+            Assert.IsNull(state.LoomStockpileTurn);
+        }
+
+        [TestMethod]
+        public void ClaimingTheLoomReplacesTheHand()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var field = state.Fields[1];
+            field.Luminary = new Luminary(LuminaryName.TheLoom) { IsRevealed = true };
+            var oldHand = state.CardDeck.DrawUpTo(2).ToList();
+            player.Hand.AddRange(oldHand);
+            var deckCount = state.CardDeck.Cards.Count;
+            var collect = new CollectLuminary(player, field);
+
+            collect.Perform(state);
+
+            Assert.AreEqual(4, player.Hand.Count);
+            CollectionAssert.IsSubsetOf(oldHand, state.CardDeck.Cards.ToList());
+            Assert.AreEqual(deckCount + 2 - 4, state.CardDeck.Cards.Count);
+
+            collect.Unwind(state);
+
+            CollectionAssert.AreEqual(oldHand, player.Hand);
+            Assert.AreEqual(deckCount, state.CardDeck.Cards.Count);
+        }
+
+        [TestMethod]
+        public void LoomLetsItsHolderStockpileInSpring()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var spring = state.Fields[0];
+            player.ScorePileLuminaries.Add(new Luminary(LuminaryName.TheLoom) { IsRevealed = true });
+
+            Assert.IsFalse(state.CanStockpile(spring));
+            Assert.IsTrue(state.CanStockpile(spring, player));
+            Assert.IsFalse(state.CanStockpile(spring, state.Players[1]));
+
+            state.LoomStockpileTurn = state.TurnNumber;
+
+            Assert.IsFalse(state.CanStockpile(spring, player));
+        }
+    }
+}
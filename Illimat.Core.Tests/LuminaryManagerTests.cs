@@ -0,0 +1,132 @@
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class LuminaryManagerTests
+    {
+        [TestMethod]
+        public void IslandOverridesTheMaidenInWinter()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            state.Fields[0].Luminary = new Luminary(LuminaryName.TheMaiden) { IsRevealed = true };
+            state.Fields[3].Luminary = new Luminary(LuminaryName.TheIsland) { IsRevealed = true };
+
+            var capabilities = state.Luminaries.EffectiveCapabilities(state.Fields[3]);
+
+            Assert.IsFalse(capabilities.CanSow || capabilities.CanHarvest || capabilities.CanStockpile);
+            Assert.IsTrue(state.Luminaries.EffectiveCapabilities(state.Fields[0]).CanHarvest);
+        }
+
+        [TestMethod]
+        public void ForestQueenLiftsHerFieldsSeason()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var autumn = state.Fields[2];
+
+            Assert.IsFalse(state.CanSow(autumn));
+
+            autumn.Luminary = new Luminary(LuminaryName.TheForestQueen) { IsRevealed = true };
+            var capabilities = state.Luminaries.EffectiveCapabilities(autumn);
+
+            Assert.IsTrue(capabilities.CanSow && capabilities.CanHarvest && capabilities.CanStockpile);
+        }
+
+        [TestMethod]
+        public void FaceDownLuminariesHaveNoEffect()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var autumn = state.Fields[2];
+            autumn.Luminary = new Luminary(LuminaryName.TheForestQueen);
+
+            Assert.IsFalse(state.Luminaries.EffectiveCapabilities(autumn).CanSow);
+        }
+
+        [TestMethod]
+        public void BoatInWinterOverridesTheMaiden()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            state.Fields[0].Luminary = new Luminary(LuminaryName.TheMaiden) { IsRevealed = true };
+            state.Fields[3].Luminary = new Luminary(LuminaryName.TheBoat) { IsRevealed = true };
+
+            var capabilities = state.Luminaries.EffectiveCapabilities(state.Fields[3]);
+
+            Assert.IsFalse(capabilities.CanHarvest);
+            Assert.IsTrue(capabilities.CanSow && capabilities.CanStockpile);
+            CollectionAssert.AreEqual(new[]
+            {
+                "The Maiden: Winter fields can be harvested.",
+                "The Boat: iced in for Winter, so this field can't be harvested."
+            }, capabilities.SpecialRules.ToArray());
+        }
+
+        [TestMethod]
+        public void IslandInWinterOverridesTheMaiden()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            state.Players[1].ScorePileLuminaries.Add(new Luminary(LuminaryName.TheMaiden) { IsRevealed = true });
+            state.Fields[3].Luminary = new Luminary(LuminaryName.TheIsland) { IsRevealed = true };
+
+            var capabilities = state.Luminaries.EffectiveCapabilities(state.Fields[3]);
+
+            Assert.IsFalse(capabilities.CanSow || capabilities.CanHarvest || capabilities.CanStockpile);
+            CollectionAssert.AreEqual(new[]
+            {
+                "The Maiden: Winter fields can be harvested.",
+                "The Island: this field is cut off from play."
+            }, capabilities.SpecialRules.ToArray());
+        }
+
+        [TestMethod]
+        public void ForestQueenMakesTheMaidenRedundantInHerField()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            state.Fields[0].Luminary = new Luminary(LuminaryName.TheMaiden) { IsRevealed = true };
+            state.Fields[3].Luminary = new Luminary(LuminaryName.TheForestQueen) { IsRevealed = true };
+
+            var capabilities = state.Luminaries.EffectiveCapabilities(state.Fields[3]);
+
+            Assert.IsTrue(capabilities.CanSow && capabilities.CanHarvest && capabilities.CanStockpile);
+            CollectionAssert.AreEqual(new[] { "The Forest Queen: this field ignores its season." }, capabilities.SpecialRules.ToArray());
+        }
+
+        [TestMethod]
+        public void ForestQueenFieldPlaysAsSummerInEveryOrientation()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var field = state.Fields[2];
+            var queen = new Luminary(LuminaryName.TheForestQueen) { IsRevealed = true };
+            field.Luminary = queen;
+
+            foreach (var season in new[] { Season.Spring, Season.Summer, Season.Autumn, Season.Winter })
+            {
+                field.Season = season;
+                Assert.AreEqual(Season.Summer, state.EffectiveSeason(field));
+            }
+
+            field.Luminary = null;
+
+            Assert.AreEqual(Season.Winter, state.EffectiveSeason(field));
+        }
+
+        [TestMethod]
+        public void IslandKeepsItsFieldsSeason()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var autumn = state.Fields[2];
+            autumn.Luminary = new Luminary(LuminaryName.TheIsland) { IsRevealed = true };
+
+            Assert.AreEqual(Season.Autumn, state.EffectiveSeason(autumn));
+        }
+    }
+}
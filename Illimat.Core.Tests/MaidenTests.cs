@@ -0,0 +1,53 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class MaidenTests
+    {
+        [TestMethod]
+        public void FaceUpMaidenAllowsWinterHarvest()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var winter = state.Fields[3];
+            Assert.IsFalse(state.CanHarvest(winter));
+
+            state.Fields[0].Luminary = new Luminary(LuminaryName.TheMaiden) { IsRevealed = true };
+
+            Assert.IsTrue(state.CanHarvest(winter));
+        }
+
+        [TestMethod]
+        public void ClaimedMaidenKeepsAllowingWinterHarvest()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+
+            state.Players[0].ScorePileLuminaries.Add(new Luminary(LuminaryName.TheMaiden) { IsRevealed = true });
+
+            Assert.IsTrue(state.CanHarvest(state.Fields[3]));
+        }
+
+        [TestMethod]
+        public void DiscardingMaidenBlocksWinterHarvestAgain()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var winter = state.Fields[3];
+            state.Fields[0].Luminary = new Luminary(LuminaryName.TheMaiden) { IsRevealed = true };
+            var discard = new DiscardLuminary(game, state.Fields[0]);
+
+            discard.Perform(state);
+
+            Assert.IsNull(state.Fields[0].Luminary);
+            Assert.IsFalse(state.CanHarvest(winter));
+
+            discard.Unwind(state);
+
+            Assert.IsTrue(state.CanHarvest(winter));
+        }
+    }
+}
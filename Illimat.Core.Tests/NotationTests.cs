@@ -0,0 +1,186 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Extensions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class NotationTests
+    {
+        private static IAction RoundTrip(GameState state, Player player, IAction action, string expected)
+        {
+            Assert.AreEqual(expected, action.ToNotation());
+
+            var parsed = state.ParseNotation(player, expected);
+
+            Assert.AreEqual(action.GetType(), parsed.GetType());
+            Assert.AreEqual(expected, parsed.ToNotation());
+            return parsed;
+        }
+
+        [TestMethod]
+        public void SowRoundTrips()
+        {
+            var state = new Game(seed: 1).State!;
+            var player = state.Players[0];
+            var five = new Card(Rank.Five, Suit.Summer);
+            var ten = new Card(Rank.Ten, Suit.Winter);
+            player.Hand.AddRange(new[] { five, ten });
+
+            var parsed = (SowCards)RoundTrip(state, player, new SowCards(player, new List<Card> { five, ten }, state.Fields[1]), "S 5Su,TWi @Summer");
+
+            Assert.AreSame(state.Fields[1], parsed.Field);
+            Assert.AreSame(five, parsed.Cards[0]);
+        }
+
+        [TestMethod]
+        public void HarvestRoundTripsWithStockpilesAndTheOppositeField()
+        {
+            var state = new Game(seed: 1).State!;
+            var player = state.Players[0];
+            var seven = new Card(Rank.Seven, Suit.Spring);
+            var stockpile = new Pile(new List<Card> { new Card(Rank.Three, Suit.Autumn, true), new Card(Rank.Four, Suit.Winter, true) });
+            var far = TestGames.LoosePile(Rank.Seven, Suit.Stars);
+            state.Fields[1].Piles.Add(stockpile);
+            state.Fields[3].Piles.Add(far);
+            player.Hand.Add(seven);
+            var harvest = new HarvestCards(player, seven, state.Fields[1], new List<Pile> { stockpile }) { OppositePiles = new List<Pile> { far } };
+
+            var parsed = (HarvestCards)RoundTrip(state, player, harvest, "H 7Sp x 3Au+4Wi @Summer & 7St");
+
+            Assert.AreSame(stockpile, parsed.Piles.Single());
+            Assert.AreSame(far, parsed.OppositePiles.Single());
+        }
+
+        [TestMethod]
+        public void StockpileExchangeAndScatterRoundTrip()
+        {
+            var state = new Game(seed: 1).State!;
+            var player = state.Players[0];
+            var two = new Card(Rank.Two, Suit.Spring);
+            var fool = new Card(Rank.Fool, Suit.Stars);
+            var three = TestGames.LoosePile(Rank.Three, Suit.Winter);
+            var stockpile = new Pile(new List<Card> { new Card(Rank.Two, Suit.Autumn, true), new Card(Rank.Six, Suit.Autumn, true) });
+            var nine = TestGames.LoosePile(Rank.Nine, Suit.Summer);
+            state.Fields[0].Piles.AddRange(new[] { three, stockpile });
+            state.Fields[1].Piles.Add(nine);
+            player.Hand.AddRange(new[] { two, fool });
+
+            RoundTrip(state, player, new StockpileCards(player, two, state.Fields[0], new List<Pile> { three }), "P 2Sp x 3Wi @Spring");
+            var claimExchange = new ChangelingClaimExchange(player, new[]
+            {
+                new ExchangeCard(player, state.Fields[0], fool, three.Cards[0]),
+                new ExchangeCard(player, state.Fields[1], two, nine.Cards[0])
+            });
+            RoundTrip(state, player, claimExchange, "E FSt x 3Wi @Spring; 2Sp x 9Su @Summer");
+            RoundTrip(state, player, new ChangelingExchange(player, state.Fields[0], fool, three.Cards[0]), "C FSt x 3Wi @Spring");
+            var scatter = (ScatterStockpile)RoundTrip(state, player, new ScatterStockpile(player, state.Fields[0], stockpile), "X 2Au+6Au @Spring");
+
+            Assert.AreSame(stockpile, scatter.Stockpile);
+        }
+
+        [TestMethod]
+        public void LuminaryAndOkusMovesRoundTrip()
+        {
+            var state = new Game(seed: 1).State!;
+            var player = state.Players[0];
+            var victim = state.Players[2];
+
+            RoundTrip(state, player, new CollectLuminary(player, state.Fields[2]), "L @Autumn");
+            var steal = (StealOkus)RoundTrip(state, player, new StealOkus(player, victim), $"O {victim.Name}");
+            RoundTrip(state, player, new StealOkus(player, null), "O Illimat");
+
+            Assert.AreSame(victim, steal.Victim);
+        }
+
+        [TestMethod]
+        public void BareRankResolvesWhenOnlyOneCardMatches()
+        {
+            var state = new Game(seed: 1).State!;
+            var player = state.Players[0];
+            var five = new Card(Rank.Five, Suit.Summer);
+            var target = TestGames.LoosePile(Rank.Five, Suit.Autumn);
+            state.Fields[1].Piles.Add(target);
+            player.Hand.AddRange(new[] { five, new Card(Rank.Nine, Suit.Winter) });
+
+            var harvest = (HarvestCards)state.ParseNotation(player, "h 5 x 5 @1");
+
+            Assert.AreSame(five, harvest.Cards.Single());
+            Assert.AreSame(target, harvest.Piles.Single());
+        }
+
+        [TestMethod]
+        public void AmbiguousOrMissingReferencesAreRejected()
+        {
+            var state = new Game(seed: 1).State!;
+            var player = state.Players[0];
+            player.Hand.AddRange(new[] { new Card(Rank.Five, Suit.Summer), new Card(Rank.Five, Suit.Winter) });
+
+            Assert.AreEqual(IllimatError.AmbiguousCard,
+                Assert.ThrowsException<IllimatException>(() => state.ParseNotation(player, "S 5 @Summer")).Error);
+            Assert.AreEqual(IllimatError.CardNotInHand,
+                Assert.ThrowsException<IllimatException>(() => state.ParseNotation(player, "S 7Au @Summer")).Error);
+            Assert.AreEqual(IllimatError.InvalidField,
+                Assert.ThrowsException<IllimatException>(() => state.ParseNotation(player, "S 5Su @Monsoon")).Error);
+            Assert.AreEqual(IllimatError.InvalidField,
+                Assert.ThrowsException<IllimatException>(() => state.ParseNotation(player, "S 5Su @4")).Error);
+            Assert.AreEqual(IllimatError.InvalidNotation,
+                Assert.ThrowsException<IllimatException>(() => state.ParseNotation(player, "S 5Su x 5Wi @Summer")).Error);
+        }
+
+        [TestMethod]
+        public void RakeSowRoundTripsWithItsDestination()
+        {
+            var state = new Game(seed: 1).State!;
+            var player = state.Players[0];
+            var five = new Card(Rank.Five, Suit.Summer);
+            var three = TestGames.LoosePile(Rank.Three, Suit.Autumn);
+            state.Fields[1].Piles.Add(three);
+            player.Hand.Add(five);
+
+            var parsed = (RakeSow)RoundTrip(state, player, new RakeSow(player, five, state.Fields[1], three, state.Fields[2]), "R 5Su x 3Au @Summer > Autumn");
+
+            Assert.AreSame(three, parsed.RakedPile);
+            Assert.AreSame(state.Fields[2], parsed.Destination);
+        }
+
+        [TestMethod]
+        public void OkusCollectionAndPlacementRoundTrip()
+        {
+            var state = new Game(seed: 1).State!;
+            var player = state.Players[0];
+            var okus = new Okus(state.Players[2], "Grey okus");
+            state.IllimatOkuses.Add(okus);
+
+            var collect = (CollectOkus)RoundTrip(state, player, new CollectOkus(player, okus), "K Grey okus");
+            RoundTrip(state, player, new PlaceOkus(player), "Q");
+
+            Assert.AreSame(okus, collect.Okus);
+            Assert.AreEqual(IllimatError.NoOkus,
+                Assert.ThrowsException<IllimatException>(() => state.ParseNotation(player, "K Gold okus")).Error);
+        }
+
+        [TestMethod]
+        public void GameMovesRoundTripWithoutAPlayer()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+
+            var parsedScore = state.ParseGameNotation(new ScoreRound(game).ToNotation());
+            var reveal = (RevealLuminary)state.ParseGameNotation(new RevealLuminary(game, state.Fields[2]).ToNotation());
+            var turn = (ChangeSeason)state.ParseGameNotation(new ChangeSeason(game, Season.Winter, 1, true).ToNotation());
+
+            Assert.IsInstanceOfType(parsedScore, typeof(ScoreRound));
+            Assert.AreSame(game, reveal.Actor);
+            Assert.AreSame(state.Fields[2], reveal.Field);
+            Assert.AreEqual("T @1 > Winter !", turn.ToNotation());
+            Assert.AreEqual(IllimatError.InvalidNotation,
+                Assert.ThrowsException<IllimatException>(() => state.ParseNotation(state.Players[0], "Z")).Error);
+            Assert.AreEqual(IllimatError.InvalidNotation,
+                Assert.ThrowsException<IllimatException>(() => state.ParseGameNotation("B")).Error);
+        }
+    }
+}
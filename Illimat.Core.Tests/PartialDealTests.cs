@@ -0,0 +1,62 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class PartialDealTests
+    {
+        private static void ShrinkDeck(GameState state, int size)
+        {
+            while (state.CardDeck.Cards.Count > size) state.CardDeck.Cards.RemoveAt(0);
+        }
+
+        [TestMethod]
+        public void DealHandFromTinyDeckDealsWhatIsLeftAndWarns()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            ShrinkDeck(state, 2);
+            var events = new List<GameEvent>();
+            game.EventRaised += events.Add;
+
+            new DealHand(state.Players[0], state.Players[1], 4).Perform(state);
+
+            Assert.AreEqual(2, state.Players[1].Hand.Count);
+            Assert.AreEqual(0, state.CardDeck.Cards.Count);
+            Assert.AreEqual(GameEventType.PartialDeal, events.Single().Type);
+        }
+
+        [TestMethod]
+        public void SeedFieldFromTinyDeckDealsWhatIsLeftAndWarns()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            ShrinkDeck(state, 1);
+            var events = new List<GameEvent>();
+            game.EventRaised += events.Add;
+
+            new SeedField(game, state.Fields[0]).Perform(state);
+
+            Assert.AreEqual(1, state.Fields[0].Piles.Count);
+            Assert.AreEqual(GameEventType.PartialDeal, events.Single().Type);
+        }
+
+        [TestMethod]
+        public void FullDealRaisesNoWarning()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var events = new List<GameEvent>();
+            game.EventRaised += events.Add;
+
+            new DealHand(state.Players[0], state.Players[1], 4).Perform(state);
+
+            Assert.AreEqual(4, state.Players[1].Hand.Count);
+            Assert.IsFalse(events.Any());
+        }
+    }
+}
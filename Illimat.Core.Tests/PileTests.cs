@@ -0,0 +1,93 @@
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class PileTests
+    {
+        private static List<List<Pile>> Combinations(IList<Pile> piles, Card card) =>
+            Pile.HarvestCombinations(piles, card).Select(x => x.ToList()).ToList();
+
+        private static bool Includes(List<List<Pile>> combinations, params Pile[] piles) =>
+            combinations.Any(x => x.Count == piles.Length && piles.All(x.Contains));
+
+        [TestMethod]
+        public void CardHarvestsAMatchDirectlyAndThroughASum()
+        {
+            var seven = TestGames.LoosePile(Rank.Seven, Suit.Spring);
+            var three = TestGames.LoosePile(Rank.Three, Suit.Summer);
+            var four = TestGames.LoosePile(Rank.Four, Suit.Autumn);
+            var nine = TestGames.LoosePile(Rank.Nine, Suit.Winter);
+
+            var combinations = Combinations(new List<Pile> { seven, three, four, nine }, new Card(Rank.Seven, Suit.Stars));
+
+            Assert.AreEqual(3, combinations.Count);
+            Assert.IsTrue(Includes(combinations, seven));
+            Assert.IsTrue(Includes(combinations, three, four));
+            Assert.IsTrue(Includes(combinations, seven, three, four));
+        }
+
+        [TestMethod]
+        public void StockpilesAreHarvestedWhole()
+        {
+            var stockpile = new Pile(new List<Card> { new Card(Rank.Two, Suit.Spring, true), new Card(Rank.Six, Suit.Summer, true) });
+            var two = TestGames.LoosePile(Rank.Two, Suit.Autumn);
+
+            var combinations = Combinations(new List<Pile> { stockpile, two }, new Card(Rank.Eight, Suit.Winter));
+
+            Assert.AreEqual(1, combinations.Count);
+            Assert.IsTrue(Includes(combinations, stockpile));
+        }
+
+        [TestMethod]
+        public void FoolInHandHarvestsAsOneOrFourteen()
+        {
+            var ace = TestGames.LoosePile(Rank.Fool, Suit.Spring);
+            var king = TestGames.LoosePile(Rank.King, Suit.Summer);
+
+            var combinations = Combinations(new List<Pile> { ace, king }, new Card(Rank.Fool, Suit.Winter));
+
+            Assert.AreEqual(2, combinations.Count);
+            Assert.IsTrue(Includes(combinations, ace));
+            Assert.IsTrue(Includes(combinations, ace, king));
+        }
+
+        [TestMethod]
+        public void NothingToHarvestGivesNoCombinations()
+        {
+            var piles = new List<Pile> { TestGames.LoosePile(Rank.Nine, Suit.Spring), TestGames.LoosePile(Rank.Ten, Suit.Summer) };
+
+            Assert.AreEqual(0, Combinations(piles, new Card(Rank.Two, Suit.Winter)).Count);
+            Assert.AreEqual(0, Combinations(new List<Pile>(), new Card(Rank.Two, Suit.Winter)).Count);
+        }
+
+        [TestMethod]
+        public void CombinationSearchStopsAtTheLimit()
+        {
+            var piles = new List<Pile> { TestGames.LoosePile(Rank.Two, Suit.Spring), TestGames.LoosePile(Rank.Two, Suit.Summer), TestGames.LoosePile(Rank.Two, Suit.Autumn) };
+
+            var (combinations, truncated) = Pile.HarvestCombinations(piles, new Card(Rank.Two, Suit.Winter), 2);
+
+            Assert.AreEqual(2, combinations.Count);
+            Assert.IsTrue(truncated);
+            Assert.IsFalse(Pile.HarvestCombinations(piles, new Card(Rank.Two, Suit.Winter), 7).Truncated);
+        }
+
+        [TestMethod]
+        [Timeout(10000)]
+        public void CrowdedFieldSearchCompletesWithinTheCap()
+        {
+            var ranks = new[] { Rank.Two, Rank.Three, Rank.Four, Rank.Five, Rank.Six };
+            var suits = new[] { Suit.Spring, Suit.Summer, Suit.Autumn, Suit.Winter, Suit.Stars };
+            var piles = ranks.SelectMany(rank => suits, (rank, suit) => TestGames.LoosePile(rank, suit)).Take(13).ToList();
+
+            var (combinations, truncated) = Pile.HarvestCombinations(piles, new Card(Rank.King, Suit.Winter), Pile.MAX_HARVEST_COMBINATIONS);
+
+            Assert.AreEqual(Pile.MAX_HARVEST_COMBINATIONS, combinations.Count);
+            Assert.IsTrue(truncated);
+        }
+    }
+}
@@ -0,0 +1,45 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class PlayerActionStatsTests
+    {
+        [TestMethod]
+        public void ActionsCountWhatThePlayerDid()
+        {
+            var state = TestGames.New().State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var two = new Card(Rank.Two, Suit.Spring);
+            var three = new Card(Rank.Three, Suit.Autumn);
+            var five = new Card(Rank.Five, Suit.Winter);
+            var seven = new Card(Rank.Seven, Suit.Stars);
+            player.Hand.AddRange(new[] { two, three, five, seven });
+            summer.HiddenCards.Add(new Card(Rank.Nine, Suit.Summer));
+
+            new SowCards(player, new List<Card> { two }, summer).Perform(state);
+            state.TurnNumber++;
+            var stockpile = new StockpileCards(player, five, summer, new List<Pile> { summer.Piles[0] });
+            stockpile.Perform(state);
+            state.TurnNumber++;
+            var harvest = new HarvestCards(player, seven, summer, new List<Pile> { stockpile.Stockpile! });
+            harvest.Perform(state);
+
+            Assert.AreEqual(1, player.ActionStats.Sows);
+            Assert.AreEqual(1, player.ActionStats.Stockpiles);
+            Assert.AreEqual(1, player.ActionStats.Harvests);
+            Assert.AreEqual(4, player.ActionStats.CardsHarvested);
+            Assert.AreEqual(1, player.ActionStats.FieldClears);
+
+            harvest.Unwind(state);
+
+            Assert.AreEqual(0, player.ActionStats.Harvests);
+            Assert.AreEqual(0, player.ActionStats.CardsHarvested);
+            Assert.AreEqual(0, player.ActionStats.FieldClears);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class PlayoutTests
+    {
+        [TestMethod]
+        public void RandomRoundPlayoutFinishesTheRound()
+        {
+            var game = TestGames.New();
+            var luminaries = game.State!.LuminaryDeck.Cards.Count;
+
+            var scores = Playout.RandomRoundPlayout(game, new Random(7));
+
+            Assert.AreEqual(4, scores.Length);
+            Assert.IsTrue(game.State!.Players.All(x => x.Hand.Count == 0));
+            Assert.AreEqual(0, game.State!.CardDeck.Cards.Count);
+            // At worst a player is Frostbitten; at best they take every superlative, with the River turning Frostbit
+            // into a bonus, plus all five Fools, every luminary and every okus.
+            Assert.IsTrue(scores.All(x => x >= -2 && x <= 4 + 2 + 2 + 5 + luminaries + scores.Length), string.Join(",", scores));
+            // Superlatives, Fools, luminaries and okuses can't add up to more than this between four players.
+            Assert.IsTrue(scores.Sum() >= -8 && scores.Sum() <= 25, string.Join(",", scores));
+        }
+
+        [TestMethod]
+        public void SameSeedGivesTheSamePlayouts()
+        {
+            var first = Playout.RunPlayouts(3, 11);
+            var second = Playout.RunPlayouts(3, 11);
+
+            Assert.AreEqual(3, first.Rounds);
+            CollectionAssert.AreEqual(first.TotalScores.ToList(), second.TotalScores.ToList());
+            CollectionAssert.AreEqual(first.Wins.ToList(), second.Wins.ToList());
+            Assert.IsTrue(first.Wins.Sum() <= 3);
+        }
+
+        [TestMethod]
+        public void FixedSeedGivesStableCpuWinRates()
+        {
+            var config = new GameConfig { UseLuminaries = false };
+
+            var stats = Playout.RunCpuPlayouts(4, 5, config: config);
+
+            // Without luminaries every turn plays one card, so a round takes a turn for each of the 53 cards not seeded.
+            CollectionAssert.AreEqual(new[] { 0.0, 0.5, 0.25, 0.25 }, stats.WinRates.ToList());
+            CollectionAssert.AreEqual(new[] { 6, 23, 10, 13 }, stats.TotalScores.ToList());
+            Assert.AreEqual(4 * 53, stats.TotalTurns);
+            Assert.AreEqual(53.0, stats.AverageTurns);
+        }
+    }
+}
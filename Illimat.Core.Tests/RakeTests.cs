@@ -0,0 +1,98 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class RakeTests
+    {
+        [TestMethod]
+        public void RakeSowMovesALooseCardIntoTheNextField()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var spring = state.Fields[0];
+            summer.Luminary = new Luminary(LuminaryName.TheRake) { IsRevealed = true };
+            var three = TestGames.LoosePile(Rank.Three, Suit.Autumn);
+            var five = new Card(Rank.Five, Suit.Summer);
+            summer.Piles.Add(three);
+            player.Hand.Add(five);
+            var rake = new RakeSow(player, five, summer, three, spring);
+
+            rake.Perform(state);
+
+            Assert.AreEqual(five, summer.Piles.Single().Cards.Single());
+            CollectionAssert.AreEqual(new[] { three }, spring.Piles);
+            Assert.AreEqual(0, player.Hand.Count);
+
+            rake.Unwind(state);
+
+            CollectionAssert.AreEqual(new[] { three }, summer.Piles);
+            Assert.AreEqual(0, spring.Piles.Count);
+            CollectionAssert.AreEqual(new[] { five }, player.Hand);
+        }
+
+        [TestMethod]
+        public void RakeSowNeedsAFaceUpRake()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            summer.Luminary = new Luminary(LuminaryName.TheRake);
+            var three = TestGames.LoosePile(Rank.Three, Suit.Autumn);
+            var five = new Card(Rank.Five, Suit.Summer);
+            summer.Piles.Add(three);
+            player.Hand.Add(five);
+
+            var error = Assert.ThrowsException<IllimatException>(() =>
+                new RakeSow(player, five, summer, three, state.Fields[0]).Perform(state));
+
+            Assert.AreEqual(IllimatError.LuminaryUnavailable, error.Error);
+            CollectionAssert.AreEqual(new[] { five }, player.Hand);
+        }
+
+        [TestMethod]
+        public void RakeOnlyReachesNeighbouringFields()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            summer.Luminary = new Luminary(LuminaryName.TheRake) { IsRevealed = true };
+            var three = TestGames.LoosePile(Rank.Three, Suit.Autumn);
+            var five = new Card(Rank.Five, Suit.Summer);
+            summer.Piles.Add(three);
+            player.Hand.Add(five);
+
+            var error = Assert.ThrowsException<IllimatException>(() =>
+                new RakeSow(player, five, summer, three, state.Fields[3]).Perform(state));
+
+            Assert.AreEqual(IllimatError.InvalidTarget, error.Error);
+        }
+
+        [TestMethod]
+        public void LegalActionsOfferRakeSowsOnlyWithTheRake()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            summer.Piles.Add(TestGames.LoosePile(Rank.Three, Suit.Autumn));
+            player.Hand.Add(new Card(Rank.Five, Suit.Summer));
+
+            Assert.IsFalse(state.LegalActions(player).OfType<RakeSow>().Any());
+
+            summer.Luminary = new Luminary(LuminaryName.TheRake) { IsRevealed = true };
+
+            // Spring lies on one side of Summer; Autumn on the other can't be sown into.
+            var rakeSow = state.LegalActions(player).OfType<RakeSow>().Single();
+            Assert.AreSame(state.Fields[0], rakeSow.Destination);
+        }
+    }
+}
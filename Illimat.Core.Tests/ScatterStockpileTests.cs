@@ -0,0 +1,57 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class ScatterStockpileTests
+    {
+        [TestMethod]
+        public void ScatteringReturnsStockpiledCardsToTheField()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var three = new Card(Rank.Three, Suit.Winter, true);
+            var nine = new Card(Rank.Nine, Suit.Spring, true);
+            var two = new Card(Rank.Two, Suit.Spring);
+            var fieldPile = new Pile(new List<Card> { three });
+            summer.Piles.Add(fieldPile);
+            summer.Piles.Add(new Pile(new List<Card> { nine }));
+            player.Hand.AddRange(new[] { two, new Card(Rank.Five, Suit.Autumn) });
+            var stockpile = new StockpileCards(player, two, summer, new List<Pile> { fieldPile });
+            stockpile.Perform(state);
+            Assert.AreEqual(2, summer.Piles.Count);
+
+            var scatter = new ScatterStockpile(game, summer, stockpile.Stockpile!);
+            scatter.Perform(state);
+
+            Assert.AreEqual(3, summer.Piles.Count);
+            Assert.IsTrue(summer.Piles.All(x => x.Cards.Count == 1));
+            CollectionAssert.AreEquivalent(new[] { two, three, nine }, summer.Piles.Select(x => x.Cards[0]).ToList());
+
+            scatter.Unwind(state);
+
+            Assert.AreEqual(2, summer.Piles.Count);
+            Assert.AreSame(stockpile.Stockpile, summer.Piles[1]);
+        }
+
+        [TestMethod]
+        public void ScatteringALooseCardIsRejected()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var pile = new Pile(new List<Card> { new Card(Rank.Three, Suit.Winter, true) });
+            state.Fields[1].Piles.Add(pile);
+
+            var scatter = new ScatterStockpile(game, state.Fields[1], pile);
+
+            var error = Assert.ThrowsException<IllimatException>(() => scatter.Perform(state));
+            Assert.AreEqual(IllimatError.NotAStockpile, error.Error);
+        }
+    }
+}
@@ -0,0 +1,272 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class ScoreRoundTests
+    {
+        [TestMethod]
+        public void ProvisionalScoresReflectHarvestPilesAndLuminaries()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var players = state.Players;
+            players[0].Score = 5;
+            players[0].AddToHarvest(new List<Card>
+            {
+                new Card(Rank.Two, Suit.Summer),
+                new Card(Rank.Three, Suit.Summer),
+                new Card(Rank.Fool, Suit.Spring)
+            });
+            players[1].AddToHarvest(new List<Card> { new Card(Rank.Four, Suit.Winter) });
+            players[2].ScorePileLuminaries.Add(new Luminary(LuminaryName.TheRiver) { IsRevealed = true });
+
+            // Bumper Crop, Sunkissed and a Fool for the first player; Frostbit for the second.
+            CollectionAssert.AreEqual(new[] { 5 + 4 + 2 + 1, -2, 1, 0 }, state.ProvisionalScores());
+            Assert.AreEqual(5, players[0].Score);
+        }
+
+        [TestMethod]
+        public void TiedFrostbitHitsEveryTiedPlayer()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            state.Players[0].AddToHarvest(new List<Card> { new Card(Rank.Two, Suit.Winter) });
+            state.Players[1].AddToHarvest(new List<Card> { new Card(Rank.Three, Suit.Winter) });
+
+            CollectionAssert.AreEqual(new[] { -2, -2, 0, 0 }, ScoreRound.CalculateRoundScores(state));
+        }
+
+        [TestMethod]
+        public void TiedBumperCropAwardsNobody()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            state.Players[0].AddToHarvest(new List<Card> { new Card(Rank.Two, Suit.Spring), new Card(Rank.Five, Suit.Summer) });
+            state.Players[1].AddToHarvest(new List<Card> { new Card(Rank.Three, Suit.Autumn), new Card(Rank.Four, Suit.Spring) });
+
+            var preview = state.ScorePreview();
+
+            Assert.AreEqual(0, preview[0].BumperCrop);
+            Assert.AreEqual(0, preview[1].BumperCrop);
+            CollectionAssert.AreEqual(new[] { Superlative.BumperCrop }, preview[0].TiedSuperlatives.ToList());
+            CollectionAssert.AreEqual(new[] { Superlative.BumperCrop }, preview[1].TiedSuperlatives.ToList());
+            Assert.AreEqual(2, preview[0].Sunkissed);
+        }
+
+        [TestMethod]
+        public void TiedSunkissedAwardsNobody()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            state.Players[0].AddToHarvest(new List<Card> { new Card(Rank.Two, Suit.Summer), new Card(Rank.Six, Suit.Spring) });
+            state.Players[2].AddToHarvest(new List<Card> { new Card(Rank.Three, Suit.Summer) });
+
+            var preview = state.ScorePreview();
+
+            Assert.AreEqual(0, preview[0].Sunkissed);
+            Assert.AreEqual(0, preview[2].Sunkissed);
+            Assert.AreEqual(4, preview[0].BumperCrop);
+            CollectionAssert.AreEqual(new[] { Superlative.Sunkissed }, preview[2].TiedSuperlatives.ToList());
+        }
+
+        [TestMethod]
+        public void ScoreRoundAddsToTotalsAndUnwinds()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            state.Players[3].AddToHarvest(new List<Card> { new Card(Rank.Two, Suit.Summer) });
+            var scoreRound = new ScoreRound(game);
+
+            scoreRound.Perform(state);
+
+            Assert.AreEqual(6, state.Players[3].Score);
+
+            scoreRound.Unwind(state);
+
+            Assert.AreEqual(0, state.Players[3].Score);
+        }
+
+        [TestMethod]
+        public void ScoreBoundsContainTheProvisionalRoundScore()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var players = state.Players;
+            players[0].AddToHarvest(new List<Card> { new Card(Rank.Fool, Suit.Summer), new Card(Rank.Two, Suit.Summer) });
+            players[1].AddToHarvest(new List<Card> { new Card(Rank.Four, Suit.Winter) });
+            state.Fields[2].Luminary = new Luminary(LuminaryName.TheRiver);
+            state.IllimatOkuses.Add(new Okus(players[2], "A"));
+            var roundScores = ScoreRound.CalculateRoundScores(state);
+
+            for (int i = 0; i < players.Count; i++)
+            {
+                var (min, max) = state.ScoreBounds(players[i]);
+
+                Assert.IsTrue(min <= roundScores[i] && roundScores[i] <= max);
+                Assert.IsTrue(max > min);
+            }
+        }
+
+        [TestMethod]
+        public void ScoreBoundsAllowForTheRiverTurningFrostbit()
+        {
+            var state = new Game(seed: 1).State!;
+            var player = state.Players[0];
+            state.CardDeck.Cards.Clear();
+            state.LuminaryDeck.Cards.Clear();
+            player.AddToHarvest(new List<Card> { new Card(Rank.Two, Suit.Winter), new Card(Rank.Three, Suit.Summer) });
+            player.ScorePileLuminaries.Add(new Luminary(LuminaryName.TheRiver) { IsRevealed = true });
+
+            var held = state.ScoreBounds(player);
+
+            player.ScorePileLuminaries.Clear();
+            state.Fields[2].Luminary = new Luminary(LuminaryName.TheRiver);
+            var claimable = state.ScoreBounds(player);
+
+            state.Fields[2].Luminary = null;
+            state.Players[1].ScorePileLuminaries.Add(new Luminary(LuminaryName.TheRiver) { IsRevealed = true });
+            var claimedByAnother = state.ScoreBounds(player);
+
+            // Bumper Crop, Sunkissed, Frostbit turned by the River and the River itself.
+            Assert.AreEqual(4 + 2 + 2 + 1, held.Max);
+            Assert.AreEqual(4 + 2 + 2 + 1, claimable.Max);
+            Assert.AreEqual(4 + 2, claimedByAnother.Max);
+        }
+
+        [TestMethod]
+        public void ClaimedRiverTurnsFrostbitIntoPoints()
+        {
+            var withoutRiver = new Game(seed: 1).State!;
+            var withRiver = new Game(seed: 1).State!;
+
+            foreach (var state in new[] { withoutRiver, withRiver })
+            {
+                state.Players[1].AddToHarvest(new List<Card> { new Card(Rank.Four, Suit.Winter) });
+                state.Players[0].AddToHarvest(new List<Card> { new Card(Rank.Two, Suit.Spring), new Card(Rank.Three, Suit.Spring) });
+            }
+
+            withRiver.Players[1].ScorePileLuminaries.Add(new Luminary(LuminaryName.TheRiver) { IsRevealed = true });
+
+            var scoreWithout = ScoreRound.CalculateRoundScores(withoutRiver)[1];
+            var scoreWith = ScoreRound.CalculateRoundScores(withRiver)[1];
+
+            Assert.AreEqual(-2, scoreWithout);
+            // The River is itself worth a point on top of the four-point swing.
+            Assert.AreEqual(scoreWithout + 4 + 1, scoreWith);
+        }
+
+        [TestMethod]
+        public void ScorePreviewBreaksDownEachSource()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var players = state.Players;
+            players[0].AddToHarvest(new List<Card>
+            {
+                new Card(Rank.Two, Suit.Summer),
+                new Card(Rank.Fool, Suit.Winter),
+                new Card(Rank.Fool, Suit.Stars)
+            });
+            players[1].AddToHarvest(new List<Card> { new Card(Rank.Three, Suit.Summer), new Card(Rank.Four, Suit.Summer) });
+            players[1].ScorePileOkuses.Add(new Okus(players[1], "An okus"));
+            players[2].ScorePileLuminaries.Add(new Luminary(LuminaryName.TheUnion) { IsRevealed = true });
+
+            var preview = state.ScorePreview();
+
+            Assert.AreEqual(new ScoreBreakdown { BumperCrop = 4, Frostbit = -2, Fools = 2 }, preview[0]);
+            Assert.AreEqual(new ScoreBreakdown { Sunkissed = 2, Okuses = 1 }, preview[1]);
+            Assert.AreEqual(new ScoreBreakdown { Luminaries = 1 }, preview[2]);
+            Assert.AreEqual(4, preview[0].Total);
+            CollectionAssert.AreEqual(new[] { 4, 3, 1, 0 }, ScoreRound.CalculateRoundScores(state));
+        }
+
+        [TestMethod]
+        public void WinnerIsDetectedAtALowerVictoryThreshold()
+        {
+            var game = new Game(seed: 1, config: new GameConfig { VictoryThreshold = 5 });
+            var state = game.State!;
+            state.Players[2].AddToHarvest(new List<Card> { new Card(Rank.Fool, Suit.Spring), new Card(Rank.Two, Suit.Autumn) });
+            state.Players[2].Score = 0;
+
+            Assert.IsNull(state.Winner());
+
+            new ScoreRound(game).Perform(state);
+
+            Assert.AreEqual(5, state.Players[2].Score);
+            Assert.AreSame(state.Players[2], state.Winner());
+        }
+
+        [TestMethod]
+        public void TiedLeadersAtTheThresholdMeanNoWinnerYet()
+        {
+            var game = new Game(seed: 1, config: new GameConfig { VictoryThreshold = 5 });
+            var state = game.State!;
+            state.Players[0].Score = 6;
+            state.Players[1].Score = 6;
+            state.Players[2].Score = 5;
+
+            Assert.IsNull(state.Winner());
+
+            state.Players[1].Score = 7;
+
+            Assert.AreSame(state.Players[1], state.Winner());
+            Assert.AreEqual(17, new GameConfig().VictoryThreshold);
+        }
+
+        [TestMethod]
+        public void PointSourcesAreCountedPerPlayer()
+        {
+            var state = new Game(seed: 1).State!;
+            var player = state.Players[0];
+            player.ScorePileOkuses.Add(new Okus(player, "first"));
+            player.ScorePileOkuses.Add(new Okus(player, "second"));
+            player.ScorePileLuminaries.Add(new Luminary(LuminaryName.TheMaiden) { IsRevealed = true });
+            player.AddToHarvest(new[] { new Card(Rank.Fool, Suit.Spring, true), new Card(Rank.Five, Suit.Summer, true) });
+
+            Assert.AreEqual(2, player.OkusPoints);
+            Assert.AreEqual(1, player.LuminaryPoints);
+            Assert.AreEqual(1, player.FoolPoints);
+            Assert.AreEqual(0, state.Players[1].OkusPoints + state.Players[1].LuminaryPoints + state.Players[1].FoolPoints);
+        }
+
+        [TestMethod]
+        public void GameIsNotTerminalMidRound()
+        {
+            var game = TestGames.Dealt();
+            var state = game.State!;
+
+            Assert.IsFalse(state.IsTerminal());
+            Assert.AreEqual(0, state.Winners().Count);
+        }
+
+        [TestMethod]
+        public void GameIsTerminalOnceOneLeaderReachesTheThreshold()
+        {
+            var game = new Game(seed: 1, config: new GameConfig { VictoryThreshold = 5 });
+            var state = game.State!;
+            state.Players[0].Score = 4;
+            state.Players[3].Score = 5;
+
+            Assert.IsTrue(state.IsTerminal());
+            CollectionAssert.AreEqual(new[] { state.Players[3] }, state.Winners());
+        }
+
+        [TestMethod]
+        public void TiedLeadersAreAllListedButPlayGoesOn()
+        {
+            var game = new Game(seed: 1, config: new GameConfig { VictoryThreshold = 5 });
+            var state = game.State!;
+            state.Players[0].Score = 8;
+            state.Players[2].Score = 8;
+            state.Players[3].Score = 6;
+
+            CollectionAssert.AreEqual(new[] { state.Players[0], state.Players[2] }, state.Winners());
+            Assert.IsFalse(state.IsTerminal());
+        }
+    }
+}
@@ -0,0 +1,39 @@
+using Illimat.Core.Actions;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class SeenCardsTests
+    {
+        [TestMethod]
+        public void SeededCardsAreSeenByAllPlayers()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+
+            new SeedField(state.Players[0], state.Fields[0]).Perform(state);
+
+            var seededCards = state.Fields[0].Piles.SelectMany(x => x.Cards).ToList();
+            foreach (var player in state.Players)
+            {
+                Assert.IsTrue(seededCards.All(player.SeenCards.Contains));
+                Assert.IsFalse(player.SeenCards.Contains(state.CardDeck.Cards[0]));
+            }
+        }
+
+        [TestMethod]
+        public void DealtCardsAreSeenOnlyByTheirHolder()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[1];
+
+            new DealHand(state.Players[0], player, 4).Perform(state);
+
+            Assert.IsTrue(player.Hand.All(player.SeenCards.Contains));
+            Assert.IsFalse(player.Hand.Any(state.Players[2].SeenCards.Contains));
+        }
+    }
+}
@@ -0,0 +1,133 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Extensions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class SimpleCpuTests
+    {
+        [TestMethod]
+        public void ChoosesAFieldClearingHarvestOverASow()
+        {
+            var state = TestGames.New().State!;
+            var player = state.Players[0];
+            var five = new Card(Rank.Five, Suit.Stars);
+            var two = TestGames.LoosePile(Rank.Two, Suit.Spring);
+            var three = TestGames.LoosePile(Rank.Three, Suit.Autumn);
+            state.Fields[1].Piles.AddRange(new[] { two, three });
+            player.Hand.AddRange(new[] { new Card(Rank.Nine, Suit.Winter), five });
+
+            var action = new SimpleCpu().ChooseAction(state, player);
+
+            var harvest = action as HarvestCards;
+            Assert.IsNotNull(harvest);
+            Assert.AreSame(five, harvest.Cards.Single());
+            CollectionAssert.AreEquivalent(new[] { two, three }, harvest.Piles);
+        }
+
+        [TestMethod]
+        public void ClearingBeatsABiggerHarvestThatLeavesCardsBehind()
+        {
+            var state = TestGames.New().State!;
+            var player = state.Players[0];
+            var six = new Card(Rank.Six, Suit.Stars);
+            var spring = state.Fields[0];
+            var summer = state.Fields[1];
+            spring.Piles.Add(TestGames.LoosePile(Rank.Six, Suit.Spring));
+            summer.Piles.AddRange(new[] { TestGames.LoosePile(Rank.Two, Suit.Summer), TestGames.LoosePile(Rank.Four, Suit.Autumn), TestGames.LoosePile(Rank.Nine, Suit.Winter) });
+            player.Hand.Add(six);
+            var cpu = new SimpleCpu();
+
+            var clearing = new HarvestCards(player, six, spring, spring.Piles.ToList());
+            var bigger = new HarvestCards(player, six, summer, summer.Piles.Take(2).ToList());
+
+            Assert.IsTrue(cpu.EvaluateAction(state, clearing) > cpu.EvaluateAction(state, bigger));
+            Assert.IsTrue(cpu.EvaluateAction(state, bigger) > cpu.EvaluateAction(state, new SowCards(player, new List<Card> { six }, summer)));
+        }
+
+        [TestMethod]
+        public void SowsThatOpenNewHarvestsScoreLower()
+        {
+            var state = TestGames.New().State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            summer.Piles.Add(TestGames.LoosePile(Rank.Five, Suit.Autumn));
+            var cpu = new SimpleCpu();
+
+            var quiet = cpu.EvaluateAction(state, new SowCards(player, new List<Card> { new Card(Rank.Five, Suit.Stars) }, summer));
+            var feeding = cpu.EvaluateAction(state, new SowCards(player, new List<Card> { new Card(Rank.Two, Suit.Stars) }, summer));
+
+            Assert.IsTrue(quiet > feeding);
+        }
+
+        [TestMethod]
+        public void AggressiveHarvestsWhereConservativeStockpiles()
+        {
+            var state = TestGames.New().State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var three = new Card(Rank.Three, Suit.Stars);
+            var seven = new Card(Rank.Seven, Suit.Winter);
+            summer.Piles.AddRange(new[] { TestGames.LoosePile(Rank.Three, Suit.Autumn), TestGames.LoosePile(Rank.Four, Suit.Spring), TestGames.LoosePile(Rank.Nine, Suit.Winter) });
+            player.Hand.AddRange(new[] { three, seven });
+
+            var aggressive = new SimpleCpu(AIPersonality.Aggressive).ChooseAction(state, player);
+            var conservative = new SimpleCpu(AIPersonality.Conservative).ChooseAction(state, player);
+
+            Assert.AreEqual(2, ((HarvestCards)aggressive).Piles.Count);
+            Assert.AreSame(three, ((StockpileCards)conservative).Card);
+        }
+
+        [TestMethod]
+        public void SeparateCpusChooseTheSameActionFromTheSamePosition()
+        {
+            foreach (var personality in new[] { AIPersonality.Balanced, AIPersonality.Aggressive, AIPersonality.Conservative, AIPersonality.Opportunistic })
+            {
+                for (int seed = 1; seed <= 10; seed++)
+                {
+                    var game = TestGames.Dealt(seed);
+                    var state = game.State!;
+
+                    for (int turn = 0; turn < 12 && state.ActivePlayer.Hand.Count > 0; turn++)
+                    {
+                        var player = state.ActivePlayer;
+                        var first = new SimpleCpu(personality).ChooseAction(state, player);
+                        var second = new SimpleCpu(personality).ChooseAction(state, player);
+
+                        Assert.AreEqual(first.ToNotation(), second.ToNotation());
+                        Assert.AreEqual(first.ToNotation(), new SimpleCpu(personality).ChooseAction(state, player).ToNotation());
+
+                        game.Perform(first);
+                        if (!game.AdvanceTurn()) break;
+                    }
+                }
+            }
+        }
+
+        [TestMethod]
+        public void FaceCardsScoreTheTempoTheirSeasonChangeGives()
+        {
+            var state = TestGames.New().State!;
+            var player = state.Players[0];
+            var king = new Card(Rank.King, Suit.Spring);
+            player.Hand.AddRange(new[] { king, new Card(Rank.Five, Suit.Summer) });
+            state.Fields[2].Piles.Add(TestGames.LoosePile(Rank.Five, Suit.Winter));
+            var cpu = new SimpleCpu();
+
+            // Turning Summer's field to Spring puts the field with the loose Five in Summer, where it can be harvested
+            // and sown; turning Winter's field to Spring puts it in Winter, where it can't be harvested. Spring's field is
+            // in Spring already.
+            var favourable = cpu.EvaluateAction(state, new SowCards(player, new List<Card> { king }, state.Fields[1]));
+            var unturned = cpu.EvaluateAction(state, new SowCards(player, new List<Card> { king }, state.Fields[0]));
+            var unfavourable = cpu.EvaluateAction(state, new SowCards(player, new List<Card> { king }, state.Fields[3]));
+
+            Assert.IsTrue(favourable > unturned);
+            Assert.IsTrue(unturned > unfavourable);
+            CollectionAssert.AreEqual(new[] { Season.Spring, Season.Summer, Season.Autumn, Season.Winter }, state.Fields.Select(x => x.Season).ToArray());
+        }
+    }
+}
@@ -0,0 +1,134 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class StealOkusTests
+    {
+        private static Player ClaimPerfectCrime(GameState state)
+        {
+            var thief = state.Players[0];
+            var summer = state.Fields[1];
+            summer.Luminary = new Luminary(LuminaryName.ThePerfectCrime) { IsRevealed = true };
+
+            new CollectLuminary(thief, summer).Perform(state);
+
+            return thief;
+        }
+
+        [TestMethod]
+        public void StealingFromAnotherPlayerMovesTheirOkus()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var thief = ClaimPerfectCrime(state);
+            var victim = state.Players[1];
+            var okus = new Okus(victim, "B");
+            victim.ScorePileOkuses.Add(okus);
+            var steal = new StealOkus(thief, victim);
+            var events = new List<GameEvent>();
+            game.EventRaised += events.Add;
+
+            steal.Perform(state);
+
+            CollectionAssert.AreEqual(new[] { okus }, thief.ScorePileOkuses);
+            Assert.AreEqual(0, victim.ScorePileOkuses.Count);
+            Assert.AreEqual(GameEventType.OkusStolen, events.Single().Type);
+            Assert.AreSame(thief, events.Single().Actor);
+            StringAssert.Contains(events.Single().Description, victim.Name);
+
+            steal.Unwind(state);
+
+            CollectionAssert.AreEqual(new[] { okus }, victim.ScorePileOkuses);
+            Assert.AreEqual(0, thief.ScorePileOkuses.Count);
+        }
+
+        [TestMethod]
+        public void StealingFromTheIllimatTakesAnOkusFromTheCenter()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var thief = ClaimPerfectCrime(state);
+            var okus = new Okus(state.Players[2], "C");
+            state.IllimatOkuses.Add(okus);
+
+            new StealOkus(thief, null).Perform(state);
+
+            CollectionAssert.AreEqual(new[] { okus }, thief.ScorePileOkuses);
+            Assert.AreEqual(0, state.IllimatOkuses.Count);
+        }
+
+        [TestMethod]
+        public void StealingFromATargetWithNoOkusFails()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var thief = ClaimPerfectCrime(state);
+
+            var fromPlayer = Assert.ThrowsException<IllimatException>(() =>
+                new StealOkus(thief, state.Players[1]).Perform(state));
+            var fromIllimat = Assert.ThrowsException<IllimatException>(() =>
+                new StealOkus(thief, null).Perform(state));
+            Assert.AreEqual(IllimatError.NoOkus, fromPlayer.Error);
+            Assert.AreEqual(IllimatError.NoOkus, fromIllimat.Error);
+        }
+
+        [TestMethod]
+        public void StealingWithoutJustClaimingThePerfectCrimeIsRejected()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var thief = state.Players[0];
+            var victim = state.Players[1];
+            victim.ScorePileOkuses.Add(new Okus(victim, "B"));
+
+            var unclaimed = Assert.ThrowsException<IllimatException>(() => new StealOkus(thief, victim).Perform(state));
+
+            ClaimPerfectCrime(state);
+            var byAnotherPlayer = Assert.ThrowsException<IllimatException>(() =>
+                new StealOkus(state.Players[2], victim).Perform(state));
+            state.TurnNumber++;
+            var onALaterTurn = Assert.ThrowsException<IllimatException>(() => new StealOkus(thief, victim).Perform(state));
+
+            Assert.AreEqual(IllimatError.OkusNotEarned, unclaimed.Error);
+            Assert.AreEqual(IllimatError.OkusNotEarned, byAnotherPlayer.Error);
+            Assert.AreEqual(IllimatError.OkusNotEarned, onALaterTurn.Error);
+            Assert.AreEqual(1, victim.ScorePileOkuses.Count);
+        }
+
+        [TestMethod]
+        public void ClearingOntoAFaceUpPerfectCrimeAllowsOneTheft()
+        {
+            var game = TestGames.New();
+            var state = game.State!;
+            var thief = state.Players[0];
+            var victim = state.Players[1];
+            var summer = state.Fields[1];
+            var five = new Card(Rank.Five, Suit.Summer);
+            var target = new Pile(new List<Card> { new Card(Rank.Five, Suit.Autumn, true) });
+            var okuses = new[] { new Okus(victim, "B"), new Okus(victim, "C") };
+            summer.Luminary = new Luminary(LuminaryName.ThePerfectCrime) { IsRevealed = true };
+            summer.Piles.Add(target);
+            thief.Hand.Add(five);
+            victim.ScorePileOkuses.AddRange(okuses);
+
+            game.Perform(new HarvestCards(thief, five, summer, new List<Pile> { target }));
+            game.Perform(new StealOkus(thief, victim));
+
+            CollectionAssert.AreEqual(new[] { okuses[1] }, thief.ScorePileOkuses);
+            Assert.AreEqual(IllimatError.OkusNotEarned,
+                Assert.ThrowsException<IllimatException>(() => game.Perform(new StealOkus(thief, victim))).Error);
+
+            game.UndoLast();
+            game.UndoLast();
+
+            Assert.IsNull(state.PerfectCrimeTheft);
+            CollectionAssert.AreEqual(okuses, victim.ScorePileOkuses);
+        }
+    }
+}
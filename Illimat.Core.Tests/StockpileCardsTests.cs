@@ -0,0 +1,217 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class StockpileCardsTests
+    {
+        [TestMethod]
+        public void StockpileRecordsCreatorAndTurn()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var fieldPile = new Pile(new List<Card> { new Card(Rank.Three, Suit.Winter, true) });
+            var card = new Card(Rank.Two, Suit.Spring);
+            summer.Piles.Add(fieldPile);
+            player.Hand.Add(card);
+            player.Hand.Add(new Card(Rank.Five, Suit.Autumn));
+            new BeginTurn(player).Perform(state);
+            new BeginTurn(player).Perform(state);
+
+            var stockpile = new StockpileCards(player, card, summer, new List<Pile> { fieldPile });
+            stockpile.Perform(state);
+
+            Assert.AreEqual(1, summer.Piles.Count);
+            Assert.AreSame(stockpile.Stockpile, summer.Piles[0]);
+            Assert.AreSame(player, summer.Piles[0].Creator);
+            Assert.AreEqual(2, summer.Piles[0].CreatedTurn);
+            Assert.AreEqual(5, summer.Piles[0].Values.Single());
+        }
+
+        [TestMethod]
+        public void UnwindRestoresFieldAndHand()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var fieldPile = new Pile(new List<Card> { new Card(Rank.Three, Suit.Winter, true) });
+            var card = new Card(Rank.Two, Suit.Spring);
+            summer.Piles.Add(fieldPile);
+            player.Hand.Add(card);
+            player.Hand.Add(new Card(Rank.Five, Suit.Autumn));
+
+            var stockpile = new StockpileCards(player, card, summer, new List<Pile> { fieldPile });
+            stockpile.Perform(state);
+            stockpile.Unwind(state);
+
+            Assert.AreSame(fieldPile, summer.Piles[0]);
+            Assert.AreEqual(1, summer.Piles.Count);
+            CollectionAssert.Contains(player.Hand, card);
+        }
+
+        [TestMethod]
+        public void StockpileWithoutFieldPilesIsRejected()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var card = new Card(Rank.Two, Suit.Spring);
+            player.Hand.Add(card);
+            player.Hand.Add(new Card(Rank.Two, Suit.Autumn));
+
+            var stockpile = new StockpileCards(player, card, summer, new List<Pile>());
+
+            var error = Assert.ThrowsException<IllimatException>(() => stockpile.Perform(state));
+            Assert.AreEqual(IllimatError.InvalidCardCount, error.Error);
+            CollectionAssert.Contains(player.Hand, card);
+        }
+
+        [TestMethod]
+        public void StockpileWithSingleCardInHandIsRejected()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var fieldPile = new Pile(new List<Card> { new Card(Rank.Three, Suit.Winter, true) });
+            var card = new Card(Rank.Two, Suit.Spring);
+            summer.Piles.Add(fieldPile);
+            player.Hand.Add(card);
+
+            var stockpile = new StockpileCards(player, card, summer, new List<Pile> { fieldPile });
+
+            var error = Assert.ThrowsException<IllimatException>(() => stockpile.Perform(state));
+            Assert.AreEqual(IllimatError.NoMatchingStockpile, error.Error);
+            Assert.AreSame(fieldPile, summer.Piles.Single());
+        }
+
+        [TestMethod]
+        public void StockpilingFaceCardTurnsTheIllimat()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var fieldPile = new Pile(new List<Card> { new Card(Rank.Two, Suit.Winter, true) });
+            var knight = new Card(Rank.Knight, Suit.Autumn);
+            summer.Piles.Add(fieldPile);
+            player.Hand.Add(knight);
+            player.Hand.Add(new Card(Rank.King, Suit.Spring));
+
+            var stockpile = new StockpileCards(player, knight, summer, new List<Pile> { fieldPile });
+            stockpile.Perform(state);
+
+            Assert.AreEqual(Season.Autumn, state.Fields[1].Season);
+            Assert.AreEqual(Season.Winter, state.Fields[2].Season);
+            Assert.AreEqual(Season.Spring, state.Fields[3].Season);
+            Assert.AreEqual(Season.Summer, state.Fields[0].Season);
+
+            stockpile.Unwind(state);
+
+            Assert.AreEqual(Season.Summer, state.Fields[1].Season);
+        }
+
+        [TestMethod]
+        public void StockpileOptionsOnlyOfferHarvestableValues()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var summer = state.Fields[1];
+            var four = new Pile(new List<Card> { new Card(Rank.Four, Suit.Autumn, true) });
+            var king = new Pile(new List<Card> { new Card(Rank.King, Suit.Winter, true) });
+            summer.Piles.AddRange(new[] { four, king });
+
+            var options = state.StockpileOptions(summer, new Card(Rank.Three, Suit.Spring));
+
+            Assert.AreEqual(1, options.Count);
+            CollectionAssert.AreEqual(new[] { four }, options[0].Piles.ToArray());
+            Assert.AreEqual(7, options[0].Value);
+        }
+
+        [TestMethod]
+        public void StockpileOptionsForAPlayerNeedAMatchingCardInHand()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var three = new Card(Rank.Three, Suit.Spring);
+            summer.Piles.Add(new Pile(new List<Card> { new Card(Rank.Four, Suit.Autumn, true) }));
+            summer.Piles.Add(new Pile(new List<Card> { new Card(Rank.Two, Suit.Winter, true) }));
+            player.Hand.AddRange(new[] { three, new Card(Rank.Nine, Suit.Summer) });
+
+            var options = state.StockpileOptions(summer, three, player);
+
+            Assert.AreEqual(9, options.Single().Value);
+            Assert.AreEqual(2, options.Single().Piles.Count);
+            Assert.AreEqual(0, state.StockpileOptions(state.Fields[0], three, player).Count);
+        }
+
+        [TestMethod]
+        public void FreshStockpileCannotBeHarvestedUntilTheNextTurn()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var fieldPile = new Pile(new List<Card> { new Card(Rank.Three, Suit.Winter, true) });
+            var two = new Card(Rank.Two, Suit.Spring);
+            var five = new Card(Rank.Five, Suit.Autumn);
+            summer.Piles.Add(fieldPile);
+            player.Hand.AddRange(new[] { two, five });
+
+            var stockpile = new StockpileCards(player, two, summer, new List<Pile> { fieldPile });
+            stockpile.Perform(state);
+
+            Assert.AreEqual(0, state.GetHarvestOptions(five, summer).Count);
+            var error = Assert.ThrowsException<IllimatException>(() =>
+                new HarvestCards(player, five, summer, new List<Pile> { stockpile.Stockpile! }).Perform(state));
+            Assert.AreEqual(IllimatError.StockpileTooSoon, error.Error);
+
+            state.TurnNumber++;
+
+            Assert.AreEqual(1, state.GetHarvestOptions(five, summer).Count);
+            new HarvestCards(player, five, summer, new List<Pile> { stockpile.Stockpile! }).Perform(state);
+            Assert.AreEqual(0, summer.Piles.Count);
+        }
+
+        [TestMethod]
+        public void UnwindPutsTheCardBackInPlaceAndForgetsTheReveal()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var other = state.Players[1];
+            var summer = state.Fields[1];
+            var fieldPile = new Pile(new List<Card> { new Card(Rank.Three, Suit.Winter, true) });
+            var card = new Card(Rank.Two, Suit.Spring);
+            var seenBefore = new Card(Rank.Four, Suit.Stars);
+            summer.Piles.Add(fieldPile);
+            player.Hand.Add(card);
+            player.Hand.Add(new Card(Rank.Five, Suit.Autumn));
+            player.SeenCards.Add(card);
+            other.SeenCards.Add(seenBefore);
+            var handBefore = player.Hand.ToList();
+
+            var stockpile = new StockpileCards(player, card, summer, new List<Pile> { fieldPile });
+            stockpile.Perform(state);
+
+            Assert.IsTrue(state.Players.All(x => x.SeenCards.Contains(card)));
+
+            stockpile.Unwind(state);
+
+            CollectionAssert.AreEqual(handBefore, player.Hand);
+            Assert.IsTrue(player.SeenCards.Contains(card));
+            Assert.IsFalse(other.SeenCards.Contains(card));
+            Assert.IsTrue(other.SeenCards.Contains(seenBefore));
+        }
+    }
+}
@@ -0,0 +1,25 @@
+using Illimat.Core.Extensions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class SuitExtensionsTests
+    {
+        [TestMethod]
+        public void SeasonSuitsMapToTheirSeason()
+        {
+            Assert.AreEqual(Season.Spring, Suit.Spring.ToSeason());
+            Assert.AreEqual(Season.Summer, Suit.Summer.ToSeason());
+            Assert.AreEqual(Season.Autumn, Suit.Autumn.ToSeason());
+            Assert.AreEqual(Season.Winter, Suit.Winter.ToSeason());
+        }
+
+        [TestMethod]
+        public void StarsHaveNoSeason()
+        {
+            Assert.IsNull(Suit.Stars.ToSeason());
+        }
+    }
+}
@@ -0,0 +1,33 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using System.Collections.Generic;
+
+namespace Illimat.Core.Tests
+{
+    // Fixed games for tests, so nothing depends on the clock-derived default seed. New() is the position most tests
+    // start from: one human and three computer players with the initial deal queued but not performed, so every
+    // field and hand is empty. Dealt() performs the queued deal and the first BeginTurn, as Deal() does for any game.
+    // LoosePile() makes a single face-up card to place in a field.
+    internal static class TestGames
+    {
+        public const int DefaultSeed = 1;
+
+        public static Game New() => Seeded(DefaultSeed);
+
+        public static Game Seeded(int seed, GameConfig? config = null) => new(seed: seed, config: config);
+
+        public static Game Dealt(int seed = DefaultSeed, GameConfig? config = null) => Deal(Seeded(seed, config));
+
+        public static Game Deal(Game game)
+        {
+            while (game.PerformPending() is IAction action)
+            {
+                if (action is BeginTurn) break;
+            }
+
+            return game;
+        }
+
+        public static Pile LoosePile(Rank rank, Suit suit) => new(new List<Card> { new Card(rank, suit, true) });
+    }
+}
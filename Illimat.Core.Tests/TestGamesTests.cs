@@ -0,0 +1,40 @@
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System.Linq;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class TestGamesTests
+    {
+        [TestMethod]
+        public void SameSeedDealsIdenticalGames()
+        {
+            var first = TestGames.Dealt(7).State!;
+            var second = TestGames.Dealt(7).State!;
+
+            for (int i = 0; i < first.Players.Count; i++)
+            {
+                CollectionAssert.AreEqual(first.Players[i].Hand, second.Players[i].Hand);
+            }
+
+            for (int i = 0; i < first.Fields.Length; i++)
+            {
+                CollectionAssert.AreEqual(
+                    first.Fields[i].Piles.SelectMany(x => x.Cards).ToList(),
+                    second.Fields[i].Piles.SelectMany(x => x.Cards).ToList());
+            }
+
+            CollectionAssert.AreEqual(first.CardDeck.Cards.ToList(), second.CardDeck.Cards.ToList());
+        }
+
+        [TestMethod]
+        public void DealtGameFillsHandsAndFields()
+        {
+            var state = TestGames.Dealt().State!;
+
+            Assert.IsTrue(state.Players.All(x => x.Hand.Count >= 3));
+            Assert.IsTrue(state.Fields.All(x => x.Piles.Count == 3));
+            Assert.AreEqual(state.Players.Count, state.IllimatOkuses.Count);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using System;
+using System.Collections.Generic;
+
+namespace Illimat.Core.Tests
+{
+    [TestClass]
+    public class UnionTests
+    {
+        [TestMethod]
+        public void UnionHarvestsWithTheCombinedValueOfTwoCards()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            summer.Luminary = new Luminary(LuminaryName.TheUnion) { IsRevealed = true };
+            var two = new Card(Rank.Two, Suit.Spring);
+            var three = new Card(Rank.Three, Suit.Winter);
+            var target = TestGames.LoosePile(Rank.Five, Suit.Autumn);
+            summer.Piles.Add(target);
+            player.Hand.AddRange(new[] { two, three });
+
+            new HarvestCards(player, new List<Card> { two, three }, summer, new List<Pile> { target }).Perform(state);
+
+            Assert.AreEqual(0, player.Hand.Count);
+            Assert.AreEqual(3, player.HarvestPile.Count);
+        }
+
+        [TestMethod]
+        public void UnionRejectsASumThatMatchesNoTargets()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            summer.Luminary = new Luminary(LuminaryName.TheUnion) { IsRevealed = true };
+            var two = new Card(Rank.Two, Suit.Spring);
+            var three = new Card(Rank.Three, Suit.Winter);
+            var target = TestGames.LoosePile(Rank.Seven, Suit.Autumn);
+            summer.Piles.Add(target);
+            player.Hand.AddRange(new[] { two, three });
+
+            var error = Assert.ThrowsException<IllimatException>(() =>
+                new HarvestCards(player, new List<Card> { two, three }, summer, new List<Pile> { target }).Perform(state));
+            Assert.AreEqual(IllimatError.NoMatchingHarvest, error.Error);
+            Assert.AreEqual(2, player.Hand.Count);
+        }
+
+        [TestMethod]
+        public void TwoCardHarvestNeedsTheUnion()
+        {
+            var game = new Game(seed: 1);
+            var state = game.State!;
+            var player = state.Players[0];
+            var summer = state.Fields[1];
+            var two = new Card(Rank.Two, Suit.Spring);
+            var three = new Card(Rank.Three, Suit.Winter);
+            var target = TestGames.LoosePile(Rank.Five, Suit.Autumn);
+            summer.Piles.Add(target);
+            player.Hand.AddRange(new[] { two, three });
+
+            var error = Assert.ThrowsException<IllimatException>(() =>
+                new HarvestCards(player, new List<Card> { two, three }, summer, new List<Pile> { target }).Perform(state));
+            Assert.AreEqual(IllimatError.InvalidCardCount, error.Error);
+        }
+    }
+}
@@ -0,0 +1,24 @@
+﻿using Illimat.Core.Models;
+
+namespace Illimat.Core
+{
+    // What an action did beyond moving cards, so a UI can react without re-deriving it. Orientation is the season
+    // of field 0, before and after the Illimat turned. Events lists everything the action raised, in order, for
+    // animating it or sending it to other players.
+    public record class ActionOutcome
+    {
+        public bool FieldCleared { get; init; }
+        public (Season Old, Season New)? SeasonChange { get; init; }
+        public IReadOnlyList<Luminary> LuminariesRevealed { get; init; }
+        public IReadOnlyList<GameEvent> Events { get; init; }
+
+        public ActionOutcome(bool fieldCleared, (Season Old, Season New)? seasonChange, IReadOnlyList<Luminary> luminariesRevealed,
+            IReadOnlyList<GameEvent> events)
+        {
+            FieldCleared = fieldCleared;
+            SeasonChange = seasonChange;
+            LuminariesRevealed = luminariesRevealed;
+            Events = events;
+        }
+    }
+}
@@ -5,21 +5,27 @@ namespace Illimat.Core.Actions
     public class BeginTurn : IAction
     {
         public IActor Actor { get; }
+        private int PreviousPlayerIndex { get; set; }
 
         public BeginTurn(Player player)
         {
             Actor = player;
         }
 
+        public override string ToString() => $"{Actor.Name} begins their turn";
+
         public void Perform(GameState gameState)
         {
+            PreviousPlayerIndex = gameState.ActivePlayerIndex;
             gameState.ActivePlayerIndex = gameState.Players.IndexOf((Player)Actor);
+            gameState.TurnNumber++;
             // Console.WriteLine($"Begin {Actor}'s turn.");
         }
 
         public void Unwind(GameState gameState)
         {
-            gameState.ActivePlayerIndex = (gameState.Players.IndexOf((Player)Actor) - 1) % gameState.Players.Count;
+            gameState.TurnNumber--;
+            gameState.ActivePlayerIndex = PreviousPlayerIndex;
         }
     }
 }
@@ -1,4 +1,5 @@
-﻿using Illimat.Core.Models;
+﻿using Illimat.Core.Extensions;
+using Illimat.Core.Models;
 
 namespace Illimat.Core.Actions
 {
@@ -18,6 +19,18 @@ namespace Illimat.Core.Actions
             LockIllimat = lockIllimat;
         }
 
+        // Playing a suited face card turns the Illimat so that the field it was played to takes the card's season.
+        public static ChangeSeason? ForFaceCard(Player player, Card card, GameState gameState, Field field)
+        {
+            var season = card.Suit.ToSeason();
+
+            if (!card.Rank.IsFaceCard() || season == null) return null;
+
+            return new ChangeSeason(player, season.Value, Array.IndexOf(gameState.Fields, field));
+        }
+
+        public override string ToString() => $"{Actor.Name} turns the Illimat so that field {FieldIndex} is in {Season}";
+
         public void Perform(GameState gameState)
         {
             if (gameState.IllimatLockers.Count == 0)
@@ -28,6 +41,8 @@ namespace Illimat.Core.Actions
                 if (!seasonsAreAlreadyAligned)
                 {
                     AlignSeasons(gameState, Season);
+                    gameState.Game.Raise(new GameEvent(GameEventType.SeasonChanged, Actor,
+                        $"{Actor.Name} turned the Illimat so that field {FieldIndex} is in {Season}."));
                 }
 
                 // Console.WriteLine($"The Illimat {(seasonsAreAlreadyAligned ? "is already" : "has been")} aligned such that field {FieldIndex} is in {Season}.");
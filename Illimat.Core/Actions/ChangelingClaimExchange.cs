@@ -0,0 +1,61 @@
+﻿using Illimat.Core.Extensions;
+using Illimat.Core.Models;
+
+namespace Illimat.Core.Actions
+{
+    // The exchanges for claiming the Changeling, made together as one move on the turn it was claimed: each swaps a
+    // card in hand for a loose card in any field, without turning the Illimat. Every card is checked before any moves,
+    // so the exchanges are made, and undone, all at once.
+    public class ChangelingClaimExchange : IAction
+    {
+        public IActor Actor { get; }
+        public IReadOnlyList<ExchangeCard> Swaps { get; }
+        private (Player Player, int Count, int Turn)? PreviousClaimExchanges { get; set; }
+
+        public ChangelingClaimExchange(Player player, IReadOnlyList<ExchangeCard> swaps)
+        {
+            Actor = player;
+            Swaps = swaps;
+        }
+
+        public override string ToString() => $"{Actor.Name} exchanges " +
+            string.Join(" and ", Swaps.Select(x => $"{x.Lose.ToFriendlyString()} for {x.Gain.ToFriendlyString()}")) +
+            " for claiming the Changeling";
+
+        public void Perform(GameState gameState)
+        {
+            var player = (Player)Actor;
+
+            if (gameState.ChangelingClaimExchanges is not (Player claimer, int count, int turn) ||
+                !ReferenceEquals(claimer, player) || count == 0 || turn != gameState.TurnNumber)
+                throw new IllimatException(IllimatError.LuminaryUnavailable, $"{Actor} can only exchange cards this way straight after claiming the Changeling.");
+            if (Swaps.Count == 0 || Swaps.Count > count)
+                throw new IllimatException(IllimatError.InvalidCardCount, $"Claiming the Changeling allows between 1 and {count} exchanges.");
+            if (Swaps.Any(x => !ReferenceEquals(x.Actor, player)))
+                throw new IllimatException(IllimatError.WrongActor, $"Every exchange must be made by {Actor}.");
+            if (Swaps.Select(x => x.Lose).Distinct().Count() < Swaps.Count || Swaps.Select(x => x.Gain).Distinct().Count() < Swaps.Count)
+                throw new IllimatException(IllimatError.InvalidTarget, "Each exchange must swap different cards.");
+
+            foreach (var swap in Swaps) swap.Check();
+
+            PreviousClaimExchanges = gameState.ChangelingClaimExchanges;
+            gameState.ChangelingClaimExchanges = (player, count - Swaps.Count, turn);
+
+            foreach (var swap in Swaps) swap.Perform(gameState);
+        }
+
+        public void Unwind(GameState gameState)
+        {
+            if (PreviousClaimExchanges != null)
+            {
+                for (int i = Swaps.Count - 1; i >= 0; i--)
+                {
+                    Swaps[i].Unwind(gameState);
+                }
+
+                gameState.ChangelingClaimExchanges = PreviousClaimExchanges;
+                PreviousClaimExchanges = null;
+            }
+        }
+    }
+}
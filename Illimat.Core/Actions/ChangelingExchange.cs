@@ -0,0 +1,57 @@
+﻿using Illimat.Core.Extensions;
+using Illimat.Core.Models;
+
+namespace Illimat.Core.Actions
+{
+    // The Changeling's exchange: a loose card in its field for a card in hand, at most once per turn. Whoever has
+    // claimed the Changeling may make the exchange in any field.
+    public class ChangelingExchange : IAction
+    {
+        public IActor Actor { get; }
+        public Field Field { get; }
+        public Card Lose { get; }
+        public Card Gain { get; }
+        private ExchangeCard? Exchange { get; set; }
+        private int? PreviousExchangeTurn { get; set; }
+
+        public ChangelingExchange(Player player, Field field, Card lose, Card gain)
+        {
+            Actor = player;
+            Field = field;
+            Lose = lose;
+            Gain = gain;
+        }
+
+        public override string ToString() =>
+            $"{Actor.Name} uses the Changeling to exchange {Lose.ToFriendlyString()} for {Gain.ToFriendlyString()}";
+
+        public void Perform(GameState gameState)
+        {
+            var player = (Player)Actor;
+            var isFaceUpHere = Field.Luminary is { IsRevealed: true, LuminaryName: LuminaryName.TheChangeling };
+            var isClaimed = player.ScorePileLuminaries.Any(x => x.LuminaryName == LuminaryName.TheChangeling);
+
+            if (!isFaceUpHere && !isClaimed)
+                throw new IllimatException(IllimatError.LuminaryUnavailable, $"The Changeling is neither face up in field {Field} nor claimed by {Actor}.");
+            if (gameState.ChangelingExchangeTurn == gameState.TurnNumber)
+                throw new IllimatException(IllimatError.AlreadyUsedThisTurn, "The Changeling has already been used this turn.");
+
+            var exchange = new ExchangeCard(player, Field, Lose, Gain);
+            exchange.Perform(gameState);
+
+            Exchange = exchange;
+            PreviousExchangeTurn = gameState.ChangelingExchangeTurn;
+            gameState.ChangelingExchangeTurn = gameState.TurnNumber;
+        }
+
+        public void Unwind(GameState gameState)
+        {
+            if (Exchange != null)
+            {
+                Exchange.Unwind(gameState);
+                gameState.ChangelingExchangeTurn = PreviousExchangeTurn;
+                Exchange = null;
+            }
+        }
+    }
+}
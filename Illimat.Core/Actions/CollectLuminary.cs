@@ -6,6 +6,15 @@ namespace Illimat.Core.Actions
     {
         public IActor Actor { get; }
         public Field Field { get; init; }
+        private Luminary? CollectedLuminary { get; set; }
+        private List<Card>? HiddenCards { get; set; }
+        private List<Card>? DiscardedHand { get; set; }
+        private List<Card>? DrawnHand { get; set; }
+        private (Player Player, int Count, int Turn)? PreviousPerfectCrimeTheft { get; set; }
+        private (Player Player, int Count, int Turn)? PreviousChangelingClaimExchanges { get; set; }
+
+        const int LOOM_HAND_SIZE = 4;
+        const int CHANGELING_CLAIM_EXCHANGES = 2;
 
         public CollectLuminary(Player player, Field field)
         {
@@ -13,14 +22,103 @@ namespace Illimat.Core.Actions
             Field = field;
         }
 
+        public override string ToString() => $"{Actor.Name} collects a luminary";
+
         public void Perform(GameState gameState)
         {
-            throw new NotImplementedException();
+            var player = (Player)Actor;
+
+            if (Field.Luminary == null)
+                throw new IllimatException(IllimatError.LuminaryUnavailable, $"Field {Field} has no luminary to collect.");
+
+            CollectedLuminary = Field.Luminary;
+            HiddenCards = Field.HiddenCards.ToList();
+
+            Field.Luminary = null;
+            Field.HiddenCards.Clear();
+            CollectedLuminary.IsRevealed = true;
+            player.ScorePileLuminaries.Add(CollectedLuminary);
+
+            // Cards held beneath the luminary go to whoever claims it.
+            HiddenCards.ForEach(x => x.IsRevealed = true);
+            player.AddToHarvest(HiddenCards);
+            gameState.RevealToAll(HiddenCards);
+
+            Console.WriteLine($"{Actor} collected {CollectedLuminary.Name} from field {Field}" +
+                (HiddenCards.Count > 0 ? $" along with the {HiddenCards.Count} cards beneath it." : "."));
+            gameState.Game.Raise(new GameEvent(GameEventType.LuminaryClaimed, Actor,
+                $"{Actor.Name} claimed {CollectedLuminary.Name} from field {Array.IndexOf(gameState.Fields, Field)}."));
+
+            // Claiming the Loom replaces the claimer's hand: it goes to the bottom of the deck and four are drawn.
+            if (CollectedLuminary.LuminaryName == LuminaryName.TheLoom)
+            {
+                DiscardedHand = player.Hand.ToList();
+                player.Hand.Clear();
+                DiscardedHand.ForEach(x => gameState.CardDeck.Cards.Add(x));
+                DrawnHand = gameState.CardDeck.DrawUpTo(LOOM_HAND_SIZE).ToList();
+                player.Hand.AddRange(DrawnHand);
+                player.SeenCards.UnionWith(DrawnHand);
+                Console.WriteLine($"{Actor} discarded {DiscardedHand.Count} cards and drew {DrawnHand.Count} for the Loom.");
+            }
+
+            // Claiming The Perfect Crime lets the claimer steal one okus, from another player or the Illimat, this turn.
+            if (CollectedLuminary.LuminaryName == LuminaryName.ThePerfectCrime)
+            {
+                PreviousPerfectCrimeTheft = gameState.PerfectCrimeTheft;
+                gameState.PerfectCrimeTheft = (player, 1, gameState.TurnNumber);
+                Console.WriteLine($"{Actor} may steal an okus for claiming {CollectedLuminary.Name}.");
+            }
+
+            // Claiming the Changeling lets the claimer exchange two cards from hand with loose cards in any fields, both
+            // in one ChangelingClaimExchange.
+            if (CollectedLuminary.LuminaryName == LuminaryName.TheChangeling)
+            {
+                PreviousChangelingClaimExchanges = gameState.ChangelingClaimExchanges;
+                gameState.ChangelingClaimExchanges = (player, CHANGELING_CLAIM_EXCHANGES, gameState.TurnNumber);
+                Console.WriteLine($"{Actor} may exchange {CHANGELING_CLAIM_EXCHANGES} cards for claiming {CollectedLuminary.Name}.");
+            }
         }
 
         public void Unwind(GameState gameState)
         {
-            throw new NotImplementedException();
+            if (CollectedLuminary != null && HiddenCards != null)
+            {
+                var player = (Player)Actor;
+
+                if (CollectedLuminary.LuminaryName == LuminaryName.ThePerfectCrime)
+                {
+                    gameState.PerfectCrimeTheft = PreviousPerfectCrimeTheft;
+                    PreviousPerfectCrimeTheft = null;
+                }
+
+                if (CollectedLuminary.LuminaryName == LuminaryName.TheChangeling)
+                {
+                    gameState.ChangelingClaimExchanges = PreviousChangelingClaimExchanges;
+                    PreviousChangelingClaimExchanges = null;
+                }
+
+                if (DiscardedHand != null && DrawnHand != null)
+                {
+                    player.Hand.Clear();
+                    for (int i = DrawnHand.Count - 1; i >= 0; i--)
+                    {
+                        gameState.CardDeck.Cards.Insert(0, DrawnHand[i]);
+                    }
+                    DiscardedHand.ForEach(x => gameState.CardDeck.Cards.Remove(x));
+                    player.Hand.AddRange(DiscardedHand);
+                    DiscardedHand = null;
+                    DrawnHand = null;
+                }
+
+                player.RemoveFromHarvest(HiddenCards);
+                HiddenCards.ForEach(x => x.IsRevealed = false);
+                Field.HiddenCards.AddRange(HiddenCards);
+                player.ScorePileLuminaries.Remove(CollectedLuminary);
+                Field.Luminary = CollectedLuminary;
+
+                CollectedLuminary = null;
+                HiddenCards = null;
+            }
         }
     }
 }
@@ -6,6 +6,7 @@ namespace Illimat.Core.Actions
     {
         public IActor Actor { get; }
         public Okus Okus { get; init; }
+        private int IllimatIndex { get; set; } = -1;
 
         public CollectOkus(Player player, Okus okus)
         {
@@ -13,14 +14,41 @@ namespace Illimat.Core.Actions
             Okus = okus;
         }
 
+        public override string ToString() => $"{Actor.Name} collects okus {Okus.Description}";
+
         public void Perform(GameState gameState)
         {
-            throw new NotImplementedException();
+            var player = (Player)Actor;
+
+            var index = gameState.IllimatOkuses.IndexOf(Okus);
+
+            if (index < 0)
+                throw new IllimatException(IllimatError.NoOkus, $"Okus {Okus.Description} is not on the Illimat.");
+            if (gameState.OkusCollection is not (Player collector, int count, int turn) ||
+                !ReferenceEquals(collector, player) || count == 0 || turn != gameState.TurnNumber)
+                throw new IllimatException(IllimatError.OkusNotEarned, $"{Actor} can only collect an okus straight after clearing a field.");
+
+            IllimatIndex = index;
+            gameState.OkusCollection = (player, count - 1, turn);
+            gameState.IllimatOkuses.RemoveAt(IllimatIndex);
+            player.ScorePileOkuses.Add(Okus);
+            Console.WriteLine($"{Actor} collected okus {Okus.Description} from the Illimat.");
+            gameState.Game.Raise(new GameEvent(GameEventType.OkusCollected, Actor,
+                $"{Actor.Name} collected okus {Okus.Description} from the Illimat."));
         }
 
         public void Unwind(GameState gameState)
         {
-            throw new NotImplementedException();
+            if (IllimatIndex >= 0)
+            {
+                var player = (Player)Actor;
+
+                player.ScorePileOkuses.Remove(Okus);
+                gameState.IllimatOkuses.Insert(IllimatIndex, Okus);
+                if (gameState.OkusCollection is (Player collector, int count, int turn))
+                    gameState.OkusCollection = (collector, count + 1, turn);
+                IllimatIndex = -1;
+            }
         }
     }
 }
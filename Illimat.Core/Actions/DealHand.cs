@@ -16,11 +16,20 @@ namespace Illimat.Core.Actions
             Count = count;
         }
 
+        public override string ToString() => $"{Actor.Name} deals {Count} cards to {Player.Name}";
+
         public void Perform(GameState gameState)
         {
             Cards = gameState.CardDeck.DrawUpTo(Count).ToList();
             Player.Hand.AddRange(Cards);
+            Player.SeenCards.UnionWith(Cards);
             Console.WriteLine($"{Actor} (as dealer) dealt {Cards.Count} cards to {Player}.");
+
+            if (Cards.Count < Count)
+            {
+                gameState.Game.Raise(new GameEvent(GameEventType.PartialDeal, Actor,
+                    $"The deck ran out while dealing to {Player.Name}; {Cards.Count} of {Count} cards were dealt."));
+            }
         }
 
         public void Unwind(GameState gameState)
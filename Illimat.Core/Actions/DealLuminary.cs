@@ -14,10 +14,20 @@ namespace Illimat.Core.Actions
             Field = field;
         }
 
+        public override string ToString() => $"{Actor.Name} deals a luminary";
+
         public void Perform(GameState gameState)
         {
             Luminary = gameState.LuminaryDeck.DrawUpTo(1).FirstOrDefault();
             Field.Luminary = Luminary;
+
+            if (Luminary != null && gameState.Game.Config.LuminaryRevealMode == LuminaryRevealMode.AtDeal)
+            {
+                Luminary.Reveal(gameState);
+                gameState.Game.Raise(new GameEvent(GameEventType.LuminaryRevealed, Actor,
+                    $"{Luminary.Name} was dealt face up to field {Array.IndexOf(gameState.Fields, Field)}."));
+            }
+
             Console.WriteLine($"{Actor} (as dealer) {(Field.Luminary != null ? "dealt" : "was unable to deal")} a Luminary to field {Field}.");
         }
 
@@ -26,6 +36,7 @@ namespace Illimat.Core.Actions
             if (Luminary != null)
             {
                 Field.Luminary = null;
+                Luminary.IsRevealed = false;
                 gameState.LuminaryDeck.Cards.Insert(0, Luminary);
             }
         }
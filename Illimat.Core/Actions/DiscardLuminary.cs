@@ -1,4 +1,5 @@
-﻿using Illimat.Core.Models;
+﻿using Illimat.Core.Extensions;
+using Illimat.Core.Models;
 
 namespace Illimat.Core.Actions
 {
@@ -14,19 +15,24 @@ namespace Illimat.Core.Actions
             Field = field;
         }
 
+        public override string ToString() => $"{Actor.Name} discards a luminary";
+
         public void Perform(GameState gameState)
         {
             var luminary = Field.Luminary;
 
+            DiscardedLuminary = luminary;
+
             if (luminary != null)
             {
                 luminary.Deactivate(gameState);
                 Field.Luminary = null;
                 Console.WriteLine($"{Actor} discarded Luminary {luminary} from field {Field}. Any ongoing effects from {luminary} are no longer active.");
+                gameState.Game.Raise(new GameEvent(GameEventType.LuminaryDiscarded, Actor,
+                    $"{Actor.Name} discarded {luminary.LuminaryName.ToFriendlyString()} from field {Array.IndexOf(gameState.Fields, Field)}."));
                 return;
             }
 
-            DiscardedLuminary = luminary;
             Console.WriteLine($"There was no Luminary in field {Field} to discard.");
         }
 
@@ -14,6 +14,8 @@ namespace Illimat.Core.Actions
             Actor = player;
         }
 
+        public override string ToString() => $"{Actor.Name} draws up to a full hand";
+
         public void Perform(GameState gameState)
         {
             var player = (Player)Actor;
@@ -22,6 +24,7 @@ namespace Illimat.Core.Actions
 
             Cards = gameState.CardDeck.DrawUpTo(count).ToList();
             player.Hand.AddRange(Cards);
+            player.SeenCards.UnionWith(Cards);
             Console.WriteLine($"{Actor} drew up {Cards.Count} cards. They now have {player.Hand.Count} cards in their hand.");
         }
 
@@ -1,30 +1,84 @@
-﻿using Illimat.Core.Models;
+﻿using Illimat.Core.Extensions;
+using Illimat.Core.Models;
 
 namespace Illimat.Core.Actions
 {
+    // A card in hand for a loose card in a field, without turning the Illimat. It is the swap the Changeling makes,
+    // whether once a turn through ChangelingExchange or for claiming it through ChangelingClaimExchange; those check
+    // that the Changeling allows it.
     public class ExchangeCard : IAction
     {
         public IActor Actor { get; }
         public Field Field { get; }
         public Card Lose { get; }
         public Card Gain { get; }
+        private int PileIndex { get; set; } = -1;
+        private Pile? ExchangedPile { get; set; }
+        private int HandIndex { get; set; }
+        private bool LoseWasRevealed { get; set; }
 
-        public ExchangeCard(IActor actor, Field field, Card lose, Card gain)
+        public ExchangeCard(Player player, Field field, Card lose, Card gain)
         {
-            Actor = actor;
+            Actor = player;
             Field = field;
             Lose = lose;
             Gain = gain;
         }
 
+        public override string ToString() => $"{Actor.Name} exchanges {Lose.ToFriendlyString()} for {Gain.ToFriendlyString()}";
+
+        // Throws unless the card to lose is in hand and the card to gain lies loose in the field, returning its pile.
+        internal Pile Check()
+        {
+            var player = (Player)Actor;
+
+            if (!player.Hand.Contains(Lose))
+                throw new IllimatException(IllimatError.CardNotInHand, $"Card {Lose} is not in {Actor}'s hand.");
+
+            var pile = Field.Piles.SingleOrDefault(x => x.Cards.Contains(Gain));
+
+            if (pile == null)
+                throw new IllimatException(IllimatError.InvalidField, $"Card {Gain} not found in field {Field}.");
+            if (pile.IsStockpile)
+                throw new IllimatException(IllimatError.StockpiledCard, $"Card {Gain} is part of a stockpile and cannot be exchanged.");
+
+            return pile;
+        }
+
         public void Perform(GameState gameState)
         {
-            throw new NotImplementedException();
+            var player = (Player)Actor;
+            var pile = Check();
+
+            PileIndex = Field.Piles.IndexOf(pile);
+            ExchangedPile = pile;
+            LoseWasRevealed = Lose.IsRevealed;
+            HandIndex = player.Hand.IndexOf(Lose);
+
+            player.Hand.RemoveAt(HandIndex);
+            Lose.IsRevealed = true;
+            Field.Piles[PileIndex] = new Pile(new List<Card> { Lose });
+            player.Hand.Add(Gain);
+            gameState.RevealToAll(new List<Card> { Lose });
+
+            Console.WriteLine($"{Actor} exchanged {Lose} from their hand for {Gain} from field {Field}.");
+            gameState.Game.Raise(new GameEvent(GameEventType.CardExchanged, Actor,
+                $"{Actor.Name} exchanged {Lose.ToShortString()} for {Gain.ToShortString()} in field {Array.IndexOf(gameState.Fields, Field)}."));
         }
 
         public void Unwind(GameState gameState)
         {
-            throw new NotImplementedException();
+            if (PileIndex >= 0 && ExchangedPile != null)
+            {
+                var player = (Player)Actor;
+
+                player.Hand.Remove(Gain);
+                Field.Piles[PileIndex] = ExchangedPile;
+                Lose.IsRevealed = LoseWasRevealed;
+                player.Hand.Insert(HandIndex, Lose);
+                PileIndex = -1;
+                ExchangedPile = null;
+            }
         }
     }
 }
@@ -15,6 +15,8 @@ namespace Illimat.Core.Actions
             Season = season;
         }
 
+        public override string ToString() => $"{Actor.Name} gives {(Season == null ? "a card" : $"a {Season} card")} from their harvest to {Recipient.Name}";
+
         public void Perform(GameState gameState)
         {
             throw new NotImplementedException();
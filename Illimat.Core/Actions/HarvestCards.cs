@@ -1,4 +1,5 @@
-﻿using Illimat.Core.Models;
+﻿using Illimat.Core.Extensions;
+using Illimat.Core.Models;
 
 namespace Illimat.Core.Actions
 {
@@ -8,6 +9,18 @@ namespace Illimat.Core.Actions
         public Field Field { get; init; }
         public List<Card> Cards { get; init; }
         public List<Pile> Piles { get; init; }
+        // Piles taken from the opposite field in the same harvest, which only a face-up Boat in Summer allows.
+        public List<Pile> OppositePiles { get; init; } = new();
+        public bool FieldCleared { get; private set; }
+        public bool OppositeFieldCleared { get; private set; }
+        public int ClearedFieldCount => (FieldCleared ? 1 : 0) + (OppositeFieldCleared ? 1 : 0);
+        private List<Pile>? PreviousPiles { get; set; }
+        private List<Pile>? PreviousOppositePiles { get; set; }
+        private List<Card>? HarvestedCards { get; set; }
+        private List<ChangeSeason>? SeasonChanges { get; set; }
+        private List<(Field Field, List<Card> Cards)>? RevealedHiddenCards { get; set; }
+        private List<IAction>? LuminaryActions { get; set; }
+        private (Player Player, int Count, int Turn)? PreviousOkusCollection { get; set; }
 
         public HarvestCards(Player player, Card card, Field field, List<Pile> piles)
         {
@@ -25,14 +38,170 @@ namespace Illimat.Core.Actions
             Piles = piles;
         }
 
+        public override string ToString() => $"{Actor.Name} harvests {Piles.Count} {(Piles.Count == 1 ? "pile" : "piles")} with {string.Join(" and ", Cards.Select(x => x.ToFriendlyString()))}";
+
         public void Perform(GameState gameState)
         {
-            throw new NotImplementedException();
+            var player = (Player)Actor;
+            var oppositeField = gameState.OppositeField(Field);
+
+            if (!gameState.CanHarvest(Field))
+                throw new IllimatException(IllimatError.SeasonBlocked, $"Cards cannot be harvested from field {Field} during {Field.Season}.");
+            var harvestCardMax = gameState.GetHarvestCardMax(Field);
+            if (Cards.Count == 0 || Cards.Count > harvestCardMax)
+                throw new IllimatException(IllimatError.InvalidCardCount, $"Harvesting from field {Field} takes between 1 and {harvestCardMax} cards from hand.");
+            if (Cards.Any(x => !player.Hand.Contains(x)))
+                throw new IllimatException(IllimatError.CardNotInHand, $"Not every card to harvest with is in {Actor}'s hand.");
+            if (Piles.Count == 0 || Piles.Any(x => !Field.Piles.Contains(x)))
+                throw new IllimatException(IllimatError.InvalidField, $"Every pile to harvest must be in field {Field}.");
+            if (OppositePiles.Count > 0 && !gameState.CanHarvestAcross(Field))
+                throw new IllimatException(IllimatError.LuminaryUnavailable, $"Only a face-up Boat in a Summer field can carry a harvest across to field {oppositeField}.");
+            if (OppositePiles.Any(x => !oppositeField.Piles.Contains(x)))
+                throw new IllimatException(IllimatError.InvalidField, $"Every pile to harvest across must be in field {oppositeField}.");
+            if (Piles.Concat(OppositePiles).Any(x => x.CreatedTurn == gameState.TurnNumber))
+                throw new IllimatException(IllimatError.StockpileTooSoon, "A stockpile can't be harvested on the turn it was made.");
+            if (gameState.Game.Config.HarvestSuitRule == HarvestSuitRule.MatchSuit &&
+                Piles.Concat(OppositePiles).SelectMany(x => x.Cards).Any(x => !Cards.Any(card => card.Suit == x.Suit)))
+                throw new IllimatException(IllimatError.SuitMismatch, $"Every harvested card must share a suit with the card played from {Actor}'s hand.");
+
+            var harvestValues = Pile.SumValues(Cards.Select(x => new Pile(new List<Card> { x })));
+            if (!harvestValues.Any(value => Pile.CanPartition(Piles, value) &&
+                (OppositePiles.Count == 0 || Pile.CanPartition(OppositePiles, value))))
+                throw new IllimatException(IllimatError.NoMatchingHarvest, $"The piles can't be harvested with a value of {string.Join(" or ", harvestValues)}.");
+
+            PreviousPiles = Field.Piles.ToList();
+            PreviousOppositePiles = oppositeField.Piles.ToList();
+            HarvestedCards = Cards
+                .Concat(Piles.SelectMany(x => x.Cards))
+                .Concat(OppositePiles.SelectMany(x => x.Cards))
+                .ToList();
+            SeasonChanges = new List<ChangeSeason>();
+            RevealedHiddenCards = new List<(Field Field, List<Card> Cards)>();
+            LuminaryActions = new List<IAction>();
+
+            Cards.ForEach(x => player.Hand.Remove(x));
+            Piles.ForEach(x => Field.Piles.Remove(x));
+            OppositePiles.ForEach(x => oppositeField.Piles.Remove(x));
+            HarvestedCards.ForEach(x => x.IsRevealed = true);
+            player.AddToHarvest(HarvestedCards);
+            gameState.RevealToAll(Cards);
+            FieldCleared = Field.Piles.Count == 0;
+            OppositeFieldCleared = OppositePiles.Count > 0 && oppositeField.Piles.Count == 0;
+
+            Console.WriteLine($"{Actor} harvested {HarvestedCards.Count} cards from field {Field}.");
+            gameState.Game.Raise(new GameEvent(GameEventType.CardsHarvested, Actor,
+                $"{Actor.Name} harvested {string.Join(", ", HarvestedCards.Select(x => x.ToShortString()))} from field {Array.IndexOf(gameState.Fields, Field)}."));
+
+            foreach (var card in Cards)
+            {
+                var seasonChange = ChangeSeason.ForFaceCard(player, card, gameState, Field);
+                if (seasonChange == null) continue;
+
+                seasonChange.Perform(gameState);
+                SeasonChanges.Add(seasonChange);
+            }
+
+            if (FieldCleared) ClearField(gameState, player, Field);
+            if (OppositeFieldCleared) ClearField(gameState, player, oppositeField);
+
+            // Each cleared field earns the harvester one okus from the Illimat, collected straight after.
+            PreviousOkusCollection = gameState.OkusCollection;
+            if (ClearedFieldCount > 0) gameState.OkusCollection = (player, ClearedFieldCount, gameState.TurnNumber);
+
+            UpdateStats(player, 1);
+        }
+
+        private void UpdateStats(Player player, int direction)
+        {
+            player.ActionStats.Harvests += direction;
+            player.ActionStats.CardsHarvested += direction * (HarvestedCards!.Count + RevealedHiddenCards!.Sum(x => x.Cards.Count));
+            player.ActionStats.FieldClears += direction * ClearedFieldCount;
+        }
+
+        private void ClearField(GameState gameState, Player player, Field field)
+        {
+            Console.WriteLine($"{Actor} cleared field {field}.");
+            gameState.Game.Raise(new GameEvent(GameEventType.FieldCleared, Actor,
+                $"{Actor.Name} cleared field {Array.IndexOf(gameState.Fields, field)}."));
+
+            if (field.HiddenCards.Count > 0)
+            {
+                // Hidden cards are awarded in the order they were placed so replays stay deterministic.
+                var hiddenCards = field.HiddenCards.ToList();
+                field.HiddenCards.Clear();
+                hiddenCards.ForEach(x => x.IsRevealed = true);
+                player.AddToHarvest(hiddenCards);
+                gameState.RevealToAll(hiddenCards);
+                RevealedHiddenCards!.Add((field, hiddenCards));
+
+                Console.WriteLine($"{Actor} revealed {hiddenCards.Count} hidden cards from field {field}.");
+                gameState.Game.Raise(new GameEvent(GameEventType.HiddenCardsRevealed, Actor,
+                    $"{Actor.Name} revealed {string.Join(", ", hiddenCards.Select(x => x.ToShortString()))} from field {Array.IndexOf(gameState.Fields, field)}."));
+            }
+
+            // Clearing a field claims its face-up luminary, or turns a face-down one over unless luminaries were dealt
+            // face up. A revealed luminary stays in the field until it is cleared again.
+            IAction? luminaryAction = field.Luminary switch
+            {
+                { IsRevealed: true } => new CollectLuminary(player, field),
+                { IsRevealed: false } when gameState.Game.Config.LuminaryRevealMode == LuminaryRevealMode.OnFieldClear =>
+                    new RevealLuminary(Actor, field),
+                _ => null
+            };
+
+            if (luminaryAction != null)
+            {
+                luminaryAction.Perform(gameState);
+                LuminaryActions!.Add(luminaryAction);
+            }
         }
 
         public void Unwind(GameState gameState)
         {
-            throw new NotImplementedException();
+            if (PreviousPiles != null && PreviousOppositePiles != null && HarvestedCards != null &&
+                SeasonChanges != null && RevealedHiddenCards != null && LuminaryActions != null)
+            {
+                var player = (Player)Actor;
+                var oppositeField = gameState.OppositeField(Field);
+
+                UpdateStats(player, -1);
+                gameState.OkusCollection = PreviousOkusCollection;
+                PreviousOkusCollection = null;
+
+                for (int i = LuminaryActions.Count - 1; i >= 0; i--)
+                {
+                    LuminaryActions[i].Unwind(gameState);
+                }
+
+                foreach (var (field, hiddenCards) in RevealedHiddenCards)
+                {
+                    player.RemoveFromHarvest(hiddenCards);
+                    hiddenCards.ForEach(x => x.IsRevealed = false);
+                    field.HiddenCards.AddRange(hiddenCards);
+                }
+
+                for (int i = SeasonChanges.Count - 1; i >= 0; i--)
+                {
+                    SeasonChanges[i].Unwind(gameState);
+                }
+
+                player.RemoveFromHarvest(HarvestedCards);
+                Field.Piles.Clear();
+                Field.Piles.AddRange(PreviousPiles);
+                oppositeField.Piles.Clear();
+                oppositeField.Piles.AddRange(PreviousOppositePiles);
+                Cards.ForEach(x => x.IsRevealed = false);
+                player.Hand.AddRange(Cards);
+
+                FieldCleared = false;
+                OppositeFieldCleared = false;
+                PreviousPiles = null;
+                PreviousOppositePiles = null;
+                HarvestedCards = null;
+                SeasonChanges = null;
+                RevealedHiddenCards = null;
+                LuminaryActions = null;
+            }
         }
     }
 }
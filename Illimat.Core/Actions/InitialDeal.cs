@@ -11,12 +11,14 @@ namespace Illimat.Core.Actions
             Actor = dealer;
         }
 
+        public override string ToString() => $"{Actor.Name} deals a new round";
+
         public void Perform(GameState gameState)
         {
             AddSeedFieldActions(gameState);
             AddDealHandActions(gameState);
             AddPlaceOkusActions(gameState);
-            AddDealLuminaryActions(gameState);
+            if (gameState.Game.Config.UseLuminaries) AddDealLuminaryActions(gameState);
         }
 
         public void Unwind(GameState gameState)
@@ -35,10 +37,15 @@ namespace Illimat.Core.Actions
         private void AddDealHandActions(GameState gameState)
         {
             var dealer = (Player)Actor;
-            if (gameState.Players.Count >= 2) gameState.Game.PendingActions.Enqueue(new DealHand(dealer, gameState.Players[1], 3));
-            if (gameState.Players.Count >= 3) gameState.Game.PendingActions.Enqueue(new DealHand(dealer, gameState.Players[2], 4));
-            if (gameState.Players.Count == 4) gameState.Game.PendingActions.Enqueue(new DealHand(dealer, gameState.Players[3], 4));
-            gameState.Game.PendingActions.Enqueue(new DealHand(dealer, gameState.Players[0], 4));
+            var dealerIndex = gameState.Players.IndexOf(dealer);
+            var count = gameState.Players.Count;
+
+            // The player to the dealer's left goes first and so is dealt one card fewer; the dealer is dealt last.
+            for (int i = 1; i <= count; i++)
+            {
+                var player = gameState.Players[(dealerIndex + i) % count];
+                gameState.Game.PendingActions.Enqueue(new DealHand(dealer, player, i == 1 && count >= 2 ? 3 : 4));
+            }
         }
 
         private static void AddPlaceOkusActions(GameState gameState)
@@ -5,20 +5,31 @@ namespace Illimat.Core.Actions
     public class PlaceOkus : IAction
     {
         public IActor Actor { get; }
+        private Okus? PlacedOkus { get; set; }
 
         public PlaceOkus(Player player)
         {
             Actor = player;
         }
 
+        public override string ToString() => $"{Actor.Name} places an okus on the Illimat";
+
         public void Perform(GameState gameState)
         {
-            throw new NotImplementedException();
+            var player = (Player)Actor;
+
+            PlacedOkus = new Okus(player, $"{player.Name}'s okus");
+            gameState.IllimatOkuses.Add(PlacedOkus);
+            Console.WriteLine($"{Actor} placed their okus on the Illimat.");
         }
 
         public void Unwind(GameState gameState)
         {
-            throw new NotImplementedException();
+            if (PlacedOkus != null)
+            {
+                gameState.IllimatOkuses.Remove(PlacedOkus);
+                PlacedOkus = null;
+            }
         }
     }
 }
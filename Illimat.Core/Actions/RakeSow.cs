@@ -0,0 +1,67 @@
+﻿using Illimat.Core.Extensions;
+using Illimat.Core.Models;
+
+namespace Illimat.Core.Actions
+{
+    // While the Rake is face up, a sow into its field may also rake one loose card from that field into a field beside
+    // it, as long as cards can be sown there.
+    public class RakeSow : IAction
+    {
+        public IActor Actor { get; }
+        public Field Field { get; }
+        public Card Card { get; }
+        public Pile RakedPile { get; }
+        public Field Destination { get; }
+        private SowCards? Sow { get; set; }
+        private int? RakedIndex { get; set; }
+
+        public RakeSow(Player player, Card card, Field field, Pile rakedPile, Field destination)
+        {
+            Actor = player;
+            Card = card;
+            Field = field;
+            RakedPile = rakedPile;
+            Destination = destination;
+        }
+
+        public override string ToString() => $"{Actor.Name} sows {Card.ToFriendlyString()} and rakes a card into the next field";
+
+        public void Perform(GameState gameState)
+        {
+            var player = (Player)Actor;
+
+            if (Field.Luminary is not { IsRevealed: true, LuminaryName: LuminaryName.TheRake })
+                throw new IllimatException(IllimatError.LuminaryUnavailable, $"Only a face-up Rake lets a sow into field {Field} rake a card onward.");
+            if (!gameState.AdjacentFields(Field).Contains(Destination))
+                throw new IllimatException(IllimatError.InvalidTarget, $"Field {Destination} isn't beside field {Field}.");
+            if (!Field.Piles.Contains(RakedPile))
+                throw new IllimatException(IllimatError.InvalidField, $"The pile to rake must be in field {Field}.");
+            if (RakedPile.IsStockpile)
+                throw new IllimatException(IllimatError.StockpiledCard, "Only a loose card can be raked, not a stockpile.");
+            if (!gameState.CanSow(Destination))
+                throw new IllimatException(IllimatError.SeasonBlocked, $"Cards cannot be raked into field {Destination} during {Destination.Season}.");
+
+            Sow = new SowCards(player, new List<Card> { Card }, Field);
+            Sow.Perform(gameState);
+
+            RakedIndex = Field.Piles.IndexOf(RakedPile);
+            Field.Piles.RemoveAt(RakedIndex.Value);
+            Destination.Piles.Add(RakedPile);
+
+            Console.WriteLine($"{Actor} raked {RakedPile.Cards[0]} from field {Field} into field {Destination}.");
+        }
+
+        public void Unwind(GameState gameState)
+        {
+            if (Sow != null && RakedIndex != null)
+            {
+                Destination.Piles.Remove(RakedPile);
+                Field.Piles.Insert(RakedIndex.Value, RakedPile);
+                Sow.Unwind(gameState);
+
+                Sow = null;
+                RakedIndex = null;
+            }
+        }
+    }
+}
@@ -6,6 +6,12 @@ namespace Illimat.Core.Actions
     {
         public IActor Actor { get; }
         public Field Field { get; }
+        private Luminary? RevealedLuminary { get; set; }
+        private List<Card>? HiddenCards { get; set; }
+        private SeedField? RiverSeed { get; set; }
+
+        const int HIDDEN_CARD_COUNT = 3;
+        const int RIVER_CARD_COUNT = 6;
 
         public RevealLuminary(IActor actor, Field field)
         {
@@ -14,14 +20,55 @@ namespace Illimat.Core.Actions
         }
 
 
+        public override string ToString() => $"{Actor.Name} reveals a luminary";
+
         public void Perform(GameState gameState)
         {
-            throw new NotImplementedException();
+            if (Field.Luminary == null)
+                throw new IllimatException(IllimatError.LuminaryUnavailable, $"Field {Field} has no luminary to reveal.");
+            if (Field.Luminary.IsRevealed)
+                throw new IllimatException(IllimatError.LuminaryUnavailable, $"{Field.Luminary.Name} in field {Field} is already face up.");
+
+            RevealedLuminary = Field.Luminary;
+            RevealedLuminary.Reveal(gameState);
+            HiddenCards = new List<Card>();
+
+            // The Children and the Soldiers keep cards face down beneath them until they are claimed.
+            if (RevealedLuminary.LuminaryName is LuminaryName.TheChildren or LuminaryName.TheSoldiers)
+            {
+                HiddenCards = gameState.CardDeck.DrawUpTo(HIDDEN_CARD_COUNT).ToList();
+                Field.HiddenCards.AddRange(HiddenCards);
+            }
+
+            // The River floods its field with fresh cards as it is revealed.
+            if (RevealedLuminary.LuminaryName == LuminaryName.TheRiver)
+            {
+                RiverSeed = new SeedField(Actor, Field, RIVER_CARD_COUNT);
+                RiverSeed.Perform(gameState);
+            }
+
+            Console.WriteLine($"{Actor} revealed {RevealedLuminary.Name} in field {Field}.");
+            gameState.Game.Raise(new GameEvent(GameEventType.LuminaryRevealed, Actor,
+                $"{RevealedLuminary.Name} was revealed in field {Array.IndexOf(gameState.Fields, Field)}."));
         }
 
         public void Unwind(GameState gameState)
         {
-            throw new NotImplementedException();
+            if (RevealedLuminary != null && HiddenCards != null)
+            {
+                RiverSeed?.Unwind(gameState);
+                RiverSeed = null;
+
+                for (int i = HiddenCards.Count - 1; i >= 0; i--)
+                {
+                    Field.HiddenCards.Remove(HiddenCards[i]);
+                    gameState.CardDeck.Cards.Insert(0, HiddenCards[i]);
+                }
+
+                RevealedLuminary.IsRevealed = false;
+                RevealedLuminary = null;
+                HiddenCards = null;
+            }
         }
     }
 }
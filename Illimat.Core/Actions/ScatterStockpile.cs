@@ -0,0 +1,47 @@
+﻿using Illimat.Core.Models;
+
+namespace Illimat.Core.Actions
+{
+    public class ScatterStockpile : IAction
+    {
+        public IActor Actor { get; }
+        public Field Field { get; }
+        public Pile Stockpile { get; }
+        private List<Pile>? PreviousPiles { get; set; }
+
+        public ScatterStockpile(IActor actor, Field field, Pile stockpile)
+        {
+            Actor = actor;
+            Field = field;
+            Stockpile = stockpile;
+        }
+
+        public override string ToString() => $"{Actor.Name} scatters a stockpile of {Stockpile.Cards.Count} cards";
+
+        public void Perform(GameState gameState)
+        {
+            if (!Field.Piles.Contains(Stockpile))
+                throw new IllimatException(IllimatError.InvalidField, $"Stockpile {Stockpile} not found in field {Field}.");
+            if (!Stockpile.IsStockpile)
+                throw new IllimatException(IllimatError.NotAStockpile, $"Pile {Stockpile} is a single card, not a stockpile.");
+
+            PreviousPiles = Field.Piles.ToList();
+
+            var index = Field.Piles.IndexOf(Stockpile);
+            Field.Piles.RemoveAt(index);
+            Field.Piles.InsertRange(index, Stockpile.Cards.Select(x => new Pile(new List<Card> { x })));
+
+            Console.WriteLine($"{Actor} scattered a stockpile of {Stockpile.Cards.Count} cards in field {Field} back into loose cards.");
+        }
+
+        public void Unwind(GameState gameState)
+        {
+            if (PreviousPiles != null)
+            {
+                Field.Piles.Clear();
+                Field.Piles.AddRange(PreviousPiles);
+                PreviousPiles = null;
+            }
+        }
+    }
+}
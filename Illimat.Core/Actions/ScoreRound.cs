@@ -5,20 +5,100 @@ namespace Illimat.Core.Actions
     public class ScoreRound : IAction
     {
         public IActor Actor { get; }
+        private int[]? RoundScores { get; set; }
 
         public ScoreRound(Game game)
         {
             Actor = game;
         }
 
+        // Bumper Crop goes to the player with the most cards, Sunkissed to the most Summer cards and Frostbit to the
+        // most Winter cards. A tie voids Bumper Crop and Sunkissed, but every player tied for the most Winter cards is
+        // Frostbitten. Fools, luminaries and okuses are worth a point apiece. Whoever has claimed the River turns a
+        // Frostbit penalty into a bonus of the same size.
+        public static ScoreBreakdown[] CalculateBreakdowns(GameState gameState)
+        {
+            var players = gameState.Players;
+            var bumperCrop = SuperlativePoints(players, Superlative.BumperCrop, x => x.HarvestSummary.CardCount);
+            var sunkissed = SuperlativePoints(players, Superlative.Sunkissed, x => x.HarvestSummary.SuitCounts[Suit.Summer]);
+            var frostbit = SuperlativePoints(players, Superlative.Frostbit, x => x.HarvestSummary.SuitCounts[Suit.Winter]);
+
+            return players.Select((x, i) => new ScoreBreakdown
+            {
+                BumperCrop = bumperCrop.Points[i],
+                Sunkissed = sunkissed.Points[i],
+                Frostbit = frostbit.Points[i],
+                Fools = x.FoolPoints,
+                Luminaries = x.LuminaryPoints,
+                Okuses = x.OkusPoints,
+                TiedSuperlatives = new[] { bumperCrop, sunkissed, frostbit }
+                    .Where(superlative => superlative.Tied.Contains(i))
+                    .Select(superlative => superlative.Superlative)
+                    .ToList()
+            }).ToArray();
+        }
+
+        public static int[] CalculateRoundScores(GameState gameState) =>
+            CalculateBreakdowns(gameState).Select(x => x.Total).ToArray();
+
+        private static (Superlative Superlative, int[] Points, List<int> Tied) SuperlativePoints(
+            List<Player> players, Superlative superlative, Func<Player, int> count)
+        {
+            var points = new int[players.Count];
+            var most = players.Max(count);
+            var leaders = Enumerable.Range(0, players.Count).Where(i => count(players[i]) == most).ToList();
+
+            if (most == 0) return (superlative, points, new List<int>());
+
+            var tied = leaders.Count > 1 ? leaders : new List<int>();
+            if (tied.Count > 0 && superlative != Superlative.Frostbit) return (superlative, points, tied);
+
+            foreach (var i in leaders)
+            {
+                points[i] = players[i].SuperlativeScoring[superlative];
+
+                if (superlative == Superlative.Frostbit && players[i].ScorePileLuminaries.Any(x => x.LuminaryName == LuminaryName.TheRiver))
+                {
+                    points[i] = Math.Abs(points[i]);
+                }
+            }
+
+            return (superlative, points, tied);
+        }
+
+        public override string ToString() => "The round is scored";
+
         public void Perform(GameState gameState)
         {
-            throw new NotImplementedException();
+            RoundScores = CalculateRoundScores(gameState);
+
+            for (int i = 0; i < gameState.Players.Count; i++)
+            {
+                gameState.Players[i].Score += RoundScores[i];
+                Console.WriteLine($"{gameState.Players[i]} scored {RoundScores[i]} this round for a total of {gameState.Players[i].Score}.");
+
+                if (RoundScores[i] != 0)
+                {
+                    gameState.Game.Raise(new GameEvent(GameEventType.ScoreChanged, gameState.Players[i],
+                        $"{gameState.Players[i].Name} scored {RoundScores[i]} this round for a total of {gameState.Players[i].Score}."));
+                }
+            }
+
+            gameState.Game.Raise(new GameEvent(GameEventType.RoundEnded, Actor,
+                $"The round ended with scores of {string.Join(", ", gameState.Players.Select(x => $"{x.Name} {x.Score}"))}."));
         }
 
         public void Unwind(GameState gameState)
         {
-            throw new NotImplementedException();
+            if (RoundScores != null)
+            {
+                for (int i = 0; i < gameState.Players.Count; i++)
+                {
+                    gameState.Players[i].Score -= RoundScores[i];
+                }
+
+                RoundScores = null;
+            }
         }
     }
 }
@@ -18,9 +18,11 @@ namespace Illimat.Core.Actions
             HiddenCount = hiddenCount;
         }
 
+        public override string ToString() => $"{Actor.Name} seeds a field with {RevealedCount} face-up and {HiddenCount} face-down cards";
+
         public void Perform(GameState gameState)
         {
-            Cards = gameState.CardDeck.DrawUpTo(RevealedCount).ToList();
+            Cards = gameState.CardDeck.DrawUpTo(RevealedCount + HiddenCount).ToList();
 
             for (int i = 0; i < Cards.Count; i++)
             {
@@ -28,7 +30,14 @@ namespace Illimat.Core.Actions
                 Field.Piles.Add(new Pile(new List<Card> { Cards[i] }));
             }
 
+            gameState.RevealToAll(Cards.Where(x => x.IsRevealed));
             Console.WriteLine($"Seeded field {Array.IndexOf(gameState.Fields, Field)} with {Cards.Count} cards.");
+
+            if (Cards.Count < RevealedCount + HiddenCount)
+            {
+                gameState.Game.Raise(new GameEvent(GameEventType.PartialDeal, Actor,
+                    $"The deck ran out while seeding field {Array.IndexOf(gameState.Fields, Field)}; {Cards.Count} of {RevealedCount + HiddenCount} cards were dealt."));
+            }
         }
 
         public void Unwind(GameState gameState)
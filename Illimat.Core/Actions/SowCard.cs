@@ -1,4 +1,5 @@
-﻿using Illimat.Core.Models;
+﻿using Illimat.Core.Extensions;
+using Illimat.Core.Models;
 
 namespace Illimat.Core.Actions
 {
@@ -7,6 +8,9 @@ namespace Illimat.Core.Actions
         public IActor Actor { get; }
         public Field Field { get; }
         public List<Card> Cards { get; }
+        private List<Pile>? SownPiles { get; set; }
+        private List<ChangeSeason>? SeasonChanges { get; set; }
+        private Card? SoldiersCard { get; set; }
 
         public SowCards(Player player, List<Card> cards, Field field)
         {
@@ -15,14 +19,83 @@ namespace Illimat.Core.Actions
             Field = field;
         }
 
+        public override string ToString() => $"{Actor.Name} sows {string.Join(", ", Cards.Select(x => x.ToFriendlyString()))}";
+
         public void Perform(GameState gameState)
         {
-            throw new NotImplementedException();
+            var player = (Player)Actor;
+
+            if (Cards.Count == 0)
+                throw new IllimatException(IllimatError.MustPlayCard, $"{Actor} must sow at least one card; passing isn't allowed.");
+            if (!gameState.CanSow(Field))
+                throw new IllimatException(IllimatError.SeasonBlocked, $"Cards cannot be sown in field {Field} during {Field.Season}.");
+            if (Cards.Any(x => !player.Hand.Contains(x)))
+                throw new IllimatException(IllimatError.CardNotInHand, $"Not every card to sow is in {Actor}'s hand.");
+
+            SownPiles = new List<Pile>();
+            SeasonChanges = new List<ChangeSeason>();
+
+            foreach (var card in Cards)
+            {
+                var pile = new Pile(new List<Card> { card });
+                player.Hand.Remove(card);
+                card.IsRevealed = true;
+                Field.Piles.Add(pile);
+                SownPiles.Add(pile);
+            }
+
+            // The Soldiers add a card from the deck face down alongside every sow into their field.
+            if (Field.Luminary is { IsRevealed: true, LuminaryName: LuminaryName.TheSoldiers })
+            {
+                SoldiersCard = gameState.CardDeck.DrawUpTo(1).SingleOrDefault();
+                if (SoldiersCard != null) Field.HiddenCards.Add(SoldiersCard);
+            }
+
+            player.ActionStats.Sows++;
+            gameState.RevealToAll(Cards);
+            Console.WriteLine($"{Actor} sowed {Cards.Count} {(Cards.Count == 1 ? "card" : "cards")} into field {Field}.");
+            gameState.Game.Raise(new GameEvent(GameEventType.CardsSown, Actor,
+                $"{Actor.Name} sowed {string.Join(", ", Cards.Select(x => x.ToShortString()))} into field {Array.IndexOf(gameState.Fields, Field)}."));
+
+            foreach (var card in Cards)
+            {
+                var seasonChange = ChangeSeason.ForFaceCard(player, card, gameState, Field);
+                if (seasonChange == null) continue;
+
+                seasonChange.Perform(gameState);
+                SeasonChanges.Add(seasonChange);
+            }
         }
 
         public void Unwind(GameState gameState)
         {
-            throw new NotImplementedException();
+            if (SownPiles != null && SeasonChanges != null)
+            {
+                var player = (Player)Actor;
+
+                for (int i = SeasonChanges.Count - 1; i >= 0; i--)
+                {
+                    SeasonChanges[i].Unwind(gameState);
+                }
+
+                if (SoldiersCard != null)
+                {
+                    Field.HiddenCards.Remove(SoldiersCard);
+                    gameState.CardDeck.Cards.Insert(0, SoldiersCard);
+                    SoldiersCard = null;
+                }
+
+                foreach (var pile in SownPiles)
+                {
+                    Field.Piles.Remove(pile);
+                    pile.Cards[0].IsRevealed = false;
+                    player.Hand.Add(pile.Cards[0]);
+                }
+
+                player.ActionStats.Sows--;
+                SownPiles = null;
+                SeasonChanges = null;
+            }
         }
     }
 }
@@ -15,6 +15,8 @@ namespace Illimat.Core.Actions
             Card = card;
         }
 
+        public override string ToString() => $"{Actor.Name} steals a card from {Victim.Name}'s hand";
+
         public void Perform(GameState gameState)
         {
             throw new NotImplementedException();
@@ -2,26 +2,60 @@
 
 namespace Illimat.Core.Actions
 {
+    // The Perfect Crime's theft, open only to whoever has just claimed it and only on that turn.
     public class StealOkus : IAction
     {
         public IActor Actor { get; }
-        public Player Victim { get; }
+        // The player to steal from, or null to take an okus still sitting on the Illimat.
+        public Player? Victim { get; }
         private Okus? Okus { get; set; }
+        private int SourceIndex { get; set; } = -1;
 
-        public StealOkus(Player player, Player victim)
+        public StealOkus(Player player, Player? victim)
         {
             Actor = player;
             Victim = victim;
         }
 
+        public override string ToString() => $"{Actor.Name} steals an okus from {Victim?.Name ?? "the Illimat"}";
+
         public void Perform(GameState gameState)
         {
-            throw new NotImplementedException();
+            var player = (Player)Actor;
+            var source = Victim?.ScorePileOkuses ?? gameState.IllimatOkuses;
+
+            if (ReferenceEquals(Victim, player))
+                throw new IllimatException(IllimatError.InvalidTarget, $"{Actor} cannot steal an okus from themselves.");
+            if (source.Count == 0)
+                throw new IllimatException(IllimatError.NoOkus, $"{Victim?.Name ?? "The Illimat"} has no okus to steal.");
+            if (gameState.PerfectCrimeTheft is not (Player thief, int count, int turn) ||
+                !ReferenceEquals(thief, player) || count == 0 || turn != gameState.TurnNumber)
+                throw new IllimatException(IllimatError.OkusNotEarned, $"{Actor} can only steal an okus straight after claiming The Perfect Crime.");
+
+            gameState.PerfectCrimeTheft = (player, count - 1, turn);
+            SourceIndex = source.Count - 1;
+            Okus = source[SourceIndex];
+            source.RemoveAt(SourceIndex);
+            player.ScorePileOkuses.Add(Okus);
+            Console.WriteLine($"{Actor} stole okus {Okus.Description} from {Victim?.Name ?? "the Illimat"}.");
+            gameState.Game.Raise(new GameEvent(GameEventType.OkusStolen, Actor,
+                $"{Actor.Name} stole okus {Okus.Description} from {Victim?.Name ?? "the Illimat"}."));
         }
 
         public void Unwind(GameState gameState)
         {
-            throw new NotImplementedException();
+            if (Okus != null)
+            {
+                var player = (Player)Actor;
+                var source = Victim?.ScorePileOkuses ?? gameState.IllimatOkuses;
+
+                player.ScorePileOkuses.Remove(Okus);
+                source.Insert(SourceIndex, Okus);
+                if (gameState.PerfectCrimeTheft is (Player thief, int count, int turn))
+                    gameState.PerfectCrimeTheft = (thief, count + 1, turn);
+                Okus = null;
+                SourceIndex = -1;
+            }
         }
     }
 }
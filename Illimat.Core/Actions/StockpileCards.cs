@@ -1,4 +1,5 @@
-﻿using Illimat.Core.Models;
+﻿using Illimat.Core.Extensions;
+using Illimat.Core.Models;
 
 namespace Illimat.Core.Actions
 {
@@ -6,23 +7,93 @@ namespace Illimat.Core.Actions
     {
         public IActor Actor { get; }
         public Field Field { get; }
-        public List<Card> Cards { get; }
+        public Card Card { get; }
+        public List<Pile> Piles { get; }
+        public Pile? Stockpile { get; private set; }
+        private List<Pile>? PreviousPiles { get; set; }
+        private ChangeSeason? SeasonChange { get; set; }
+        private bool UsedLoom { get; set; }
+        private int? PreviousLoomTurn { get; set; }
+        private int HandIndex { get; set; } = -1;
+        private List<(Player Player, Card Card)> NewlySeen { get; set; } = new();
 
-        public StockpileCards(Player player, Field field, List<Card> cards)
+        public StockpileCards(Player player, Card card, Field field, List<Pile> piles)
         {
             Actor = player;
+            Card = card;
             Field = field;
-            Cards = cards;
+            Piles = piles;
         }
 
+        public override string ToString() => $"{Actor.Name} stockpiles {Card.ToFriendlyString()} with {Piles.Count} {(Piles.Count == 1 ? "pile" : "piles")}";
+
         public void Perform(GameState gameState)
         {
-            throw new NotImplementedException();
+            var player = (Player)Actor;
+
+            var needsLoom = !gameState.CanStockpile(Field);
+            if (needsLoom && !gameState.HasLoom(player, Field))
+                throw new IllimatException(IllimatError.SeasonBlocked, $"Cards cannot be stockpiled in field {Field} during {Field.Season}.");
+            if (needsLoom && gameState.LoomStockpileTurn == gameState.TurnNumber)
+                throw new IllimatException(IllimatError.AlreadyUsedThisTurn, "The Loom has already been used this turn.");
+            if (!player.Hand.Contains(Card))
+                throw new IllimatException(IllimatError.CardNotInHand, $"Card {Card} is not in {Actor}'s hand.");
+            if (Piles.Count == 0)
+                throw new IllimatException(IllimatError.InvalidCardCount, $"Stockpiling {Card} needs at least one pile from field {Field}.");
+            if (Piles.Any(x => !Field.Piles.Contains(x)))
+                throw new IllimatException(IllimatError.InvalidField, $"Not every pile to stockpile is in field {Field}.");
+
+            var stockpileValues = Pile.SumValues(Piles.Append(new Pile(new List<Card> { Card })));
+            if (!player.Hand.Where(x => !x.Equals(Card)).Any(x => x.HarvestValues().Any(stockpileValues.Contains)))
+                throw new IllimatException(IllimatError.NoMatchingStockpile, $"{Actor} has no other card in hand to harvest a stockpile worth {string.Join(" or ", stockpileValues)}.");
+
+            PreviousPiles = Field.Piles.ToList();
+            UsedLoom = needsLoom;
+            PreviousLoomTurn = gameState.LoomStockpileTurn;
+            if (UsedLoom) gameState.LoomStockpileTurn = gameState.TurnNumber;
+
+            HandIndex = player.Hand.IndexOf(Card);
+            player.Hand.RemoveAt(HandIndex);
+            Card.IsRevealed = true;
+            Piles.ForEach(x => Field.Piles.Remove(x));
+            Stockpile = new Pile(Piles.SelectMany(x => x.Cards).Prepend(Card).ToList())
+            {
+                Creator = player,
+                CreatedTurn = gameState.TurnNumber
+            };
+            Field.Piles.Add(Stockpile);
+            player.ActionStats.Stockpiles++;
+            NewlySeen = gameState.RevealToAll(new List<Card> { Card });
+
+            Console.WriteLine($"{Actor} stockpiled {Card} in field {Field} for a value of {string.Join(" or ", Stockpile.Values)}.");
+            gameState.Game.Raise(new GameEvent(GameEventType.StockpileCreated, Actor,
+                $"{Actor.Name} stockpiled {Card.ToShortString()} in field {Array.IndexOf(gameState.Fields, Field)} for a value of {string.Join(" or ", Stockpile.Values)}."));
+
+            SeasonChange = ChangeSeason.ForFaceCard(player, Card, gameState, Field);
+            SeasonChange?.Perform(gameState);
         }
 
         public void Unwind(GameState gameState)
         {
-            throw new NotImplementedException();
+            if (PreviousPiles != null)
+            {
+                var player = (Player)Actor;
+
+                SeasonChange?.Unwind(gameState);
+                SeasonChange = null;
+                if (UsedLoom) gameState.LoomStockpileTurn = PreviousLoomTurn;
+                UsedLoom = false;
+                Field.Piles.Clear();
+                Field.Piles.AddRange(PreviousPiles);
+                Card.IsRevealed = false;
+                player.Hand.Insert(HandIndex, Card);
+                NewlySeen.ForEach(x => x.Player.SeenCards.Remove(x.Card));
+                NewlySeen = new();
+                HandIndex = -1;
+                player.ActionStats.Stockpiles--;
+                Stockpile = null;
+                PreviousPiles = null;
+            }
         }
     }
 }
@@ -24,6 +24,14 @@ namespace Illimat.Core
             IsRevealed = isRevealed;
         }
 
+        // A card's identity is its rank and suit; whether it is face up doesn't make it a different card.
+        public virtual bool Equals(Card? other) => other is not null && Rank == other.Rank && Suit == other.Suit;
+
+        public override int GetHashCode() => HashCode.Combine(Rank, Suit);
+
+        // The values the card can harvest or be counted as: a Fool may stand for 1 or 14.
+        public IList<int> HarvestValues() => Rank.Values();
+
         public static IList<Card> GetCards(IEnumerable<Suit> suitSet)
         {
             return RankSet.AllRanks
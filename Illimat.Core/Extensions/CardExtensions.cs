@@ -9,6 +9,10 @@
                 "Unk";
         }
 
+        // Names the card whether or not it is face up, for logs written from the point of view of its holder.
+        public static string ToFriendlyString(this Card card) =>
+            $"{card.Rank.ToFriendlyString()} of {card.Suit.ToFriendlyString()}";
+
         public static string ToString(this Card card)
         {
             return card.IsRevealed ?
@@ -0,0 +1,47 @@
+﻿using Illimat.Core.Models;
+using System.Text;
+
+namespace Illimat.Core.Extensions
+{
+    public static class GameStateExtensions
+    {
+        const string ILLIMAT_LABEL = "[ ILLIMAT ]";
+
+        // Describes an action against the board it was taken on, naming the field it touched where it has one.
+        public static string Describe(this GameState gameState, IAction action)
+        {
+            var field = action.TargetField();
+
+            return field == null ?
+                $"{action}." :
+                $"{action} in field {Array.IndexOf(gameState.Fields, field)} ({field.Season}).";
+        }
+
+        // Fields are drawn clockwise from the top: 0 above, 1 right, 2 below and 3 left of the Illimat. Lines always end
+        // in '\n' so the same board renders identically on every platform.
+        public static string ToBoardString(this GameState gameState)
+        {
+            var labels = gameState.Fields.Select((field, i) => ToFieldLabel(gameState, field, i)).ToArray();
+            var width = Math.Max(labels[3].Length, ILLIMAT_LABEL.Length);
+            var margin = new string(' ', width + 1);
+            var board = new StringBuilder();
+
+            board.Append(margin + labels[0]).Append('\n');
+            board.Append($"{labels[3].PadLeft(width)} {ILLIMAT_LABEL} {labels[1]}").Append('\n');
+            board.Append(margin + labels[2]).Append('\n');
+
+            return board.ToString();
+        }
+
+        private static string ToFieldLabel(GameState gameState, Field field, int index)
+        {
+            var cardCount = field.Piles.Sum(x => x.Cards.Count);
+            var season = gameState.EffectiveSeason(field);
+            var seasonLabel = season == field.Season ? $"{field.Season}" : $"{field.Season} as {season}";
+            var luminary = field.Luminary == null ? "" :
+                field.Luminary.IsRevealed ? $" <{field.Luminary.LuminaryName.ToFriendlyString()}>" : " <Luminary>";
+
+            return $"{index}: {seasonLabel} ({cardCount} {(cardCount == 1 ? "card" : "cards")}){luminary}";
+        }
+    }
+}
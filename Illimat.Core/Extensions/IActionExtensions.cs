@@ -0,0 +1,35 @@
+﻿using Illimat.Core.Actions;
+using Illimat.Core.Models;
+
+namespace Illimat.Core.Extensions
+{
+    public static class IActionExtensions
+    {
+        public static Field? TargetField(this IAction action) => action switch
+        {
+            SowCards x => x.Field,
+            RakeSow x => x.Field,
+            HarvestCards x => x.Field,
+            StockpileCards x => x.Field,
+            ExchangeCard x => x.Field,
+            ChangelingExchange x => x.Field,
+            ScatterStockpile x => x.Field,
+            SeedField x => x.Field,
+            DealLuminary x => x.Field,
+            DiscardLuminary x => x.Field,
+            RevealLuminary x => x.Field,
+            CollectLuminary x => x.Field,
+            _ => null
+        };
+
+        // The cards a move plays from hand, in the order they are played.
+        public static IReadOnlyList<Card> PlayedCards(this IAction action) => action switch
+        {
+            SowCards x => x.Cards,
+            RakeSow x => new[] { x.Card },
+            HarvestCards x => x.Cards,
+            StockpileCards x => new[] { x.Card },
+            _ => Array.Empty<Card>()
+        };
+    }
+}
@@ -0,0 +1,291 @@
+﻿using Illimat.Core.Actions;
+using Illimat.Core.Models;
+using System.Text.RegularExpressions;
+
+namespace Illimat.Core.Extensions
+{
+    // A compact text form for a player's moves, e.g. "S 5Su @Summer" to sow or "H 7Sp x 3Au,4Wi @Autumn" to harvest.
+    // Cards are written as rank then suit ("TWi", "FSt"); a bare rank is accepted when only one card could match.
+    // Piles are separated by commas and the cards of a stockpile joined with '+'. Fields are named by their season or
+    // index, and a Boat harvest lists the opposite field's piles after '&'. A Rake sow names the field the raked card
+    // goes to after '>', e.g. "R 5Su x 3Au @Summer > Autumn". "B" begins a turn and "D" draws up. "K" collects the
+    // named okus from the Illimat and "Q" places one there. "C 2Sp x 9Au @Spring" makes the Changeling's exchange, and
+    // "E" its exchanges for being claimed, separated by ';', e.g. "E 2Sp x 9Au @Spring; 3Su x TWi @Autumn". The game's
+    // own moves are "Z" to score the round, "V @Autumn" to reveal a luminary and "T @1 > Summer" to turn the Illimat,
+    // with a trailing '!' to lock it.
+    public static class NotationExtensions
+    {
+        const string ILLIMAT_NAME = "Illimat";
+
+        private static readonly Regex FieldMove = new(
+            @"^(?<code>[SHPCXLRVT])(?: (?<left>\S+))?(?: x (?<right>\S+))? @(?<field>\S+)(?: & (?<across>\S+))?(?: > (?<to>\S+))?(?<lock> !)?$",
+            RegexOptions.IgnoreCase);
+        private static readonly Regex OkusMove = new(@"^O (?<victim>.+)$", RegexOptions.IgnoreCase);
+        private static readonly Regex CollectOkusMove = new(@"^K (?<okus>.+)$", RegexOptions.IgnoreCase);
+        private static readonly Regex ClaimExchangeMove = new(@"^E (?<swaps>.+)$", RegexOptions.IgnoreCase);
+        private static readonly Regex SwapMove = new(@"^(?<left>\S+) x (?<right>\S+) @(?<field>\S+)$", RegexOptions.IgnoreCase);
+
+        public static bool HasNotation(this IAction action) => action is SowCards or RakeSow or HarvestCards or
+            StockpileCards or ChangelingClaimExchange or ChangelingExchange or ScatterStockpile or CollectLuminary or StealOkus or CollectOkus or PlaceOkus or
+            BeginTurn or DrawUp or RevealLuminary or ChangeSeason or ScoreRound;
+
+        public static string ToNotation(this IAction action) => action switch
+        {
+            SowCards x => $"S {ToNotation(x.Cards)} @{x.Field.Season}",
+            RakeSow x => $"R {ToNotation(x.Card)} x {ToNotation(x.RakedPile)} @{x.Field.Season} > {x.Destination.Season}",
+            HarvestCards x => $"H {ToNotation(x.Cards)} x {ToNotation(x.Piles)} @{x.Field.Season}" +
+                (x.OppositePiles.Count > 0 ? $" & {ToNotation(x.OppositePiles)}" : ""),
+            StockpileCards x => $"P {ToNotation(x.Card)} x {ToNotation(x.Piles)} @{x.Field.Season}",
+            ChangelingClaimExchange x =>
+                "E " + string.Join("; ", x.Swaps.Select(swap => $"{ToNotation(swap.Lose)} x {ToNotation(swap.Gain)} @{swap.Field.Season}")),
+            ChangelingExchange x => $"C {ToNotation(x.Lose)} x {ToNotation(x.Gain)} @{x.Field.Season}",
+            ScatterStockpile x => $"X {ToNotation(x.Stockpile)} @{x.Field.Season}",
+            CollectLuminary x => $"L @{x.Field.Season}",
+            StealOkus x => $"O {x.Victim?.Name ?? ILLIMAT_NAME}",
+            CollectOkus x => $"K {x.Okus.Description}",
+            PlaceOkus => "Q",
+            BeginTurn => "B",
+            DrawUp => "D",
+            RevealLuminary x => $"V @{x.Field.Season}",
+            ChangeSeason x => $"T @{x.FieldIndex} > {x.Season}" + (x.LockIllimat ? " !" : ""),
+            ScoreRound => "Z",
+            _ => throw new ArgumentException($"{action.GetType().Name} has no notation.")
+        };
+
+        public static IAction ParseNotation(this GameState gameState, Player player, string notation) =>
+            Parse(gameState, player, notation);
+
+        // Reads a move the game made itself rather than any player, such as scoring the round.
+        public static IAction ParseGameNotation(this GameState gameState, string notation) =>
+            Parse(gameState, gameState.Game, notation);
+
+        private static IAction Parse(GameState gameState, IActor actor, string notation)
+        {
+            var text = notation.Trim();
+
+            if (text.Equals("Z", StringComparison.OrdinalIgnoreCase))
+                return actor is Game game ? new ScoreRound(game) :
+                    throw new IllimatException(IllimatError.InvalidNotation, "Only the game can score the round.");
+
+            var match = FieldMove.Match(text);
+            if (match.Success && match.Groups["code"].Value.ToUpperInvariant() is "V" or "T")
+                return ParseIllimatMove(gameState, actor, notation, match);
+
+            var player = actor as Player ??
+                throw new IllimatException(IllimatError.InvalidNotation, $"'{notation}' is a player's move, not the game's.");
+
+            if (text.Equals("B", StringComparison.OrdinalIgnoreCase)) return new BeginTurn(player);
+            if (text.Equals("D", StringComparison.OrdinalIgnoreCase)) return new DrawUp(player);
+            if (text.Equals("Q", StringComparison.OrdinalIgnoreCase)) return new PlaceOkus(player);
+
+            var okusMatch = OkusMove.Match(text);
+            if (okusMatch.Success) return new StealOkus(player, ParseVictim(gameState, player, okusMatch.Groups["victim"].Value));
+
+            var collectMatch = CollectOkusMove.Match(text);
+            if (collectMatch.Success) return new CollectOkus(player, ParseIllimatOkus(gameState, collectMatch.Groups["okus"].Value));
+
+            var claimExchangeMatch = ClaimExchangeMove.Match(text);
+            if (claimExchangeMatch.Success)
+                return new ChangelingClaimExchange(player, claimExchangeMatch.Groups["swaps"].Value.Split(';')
+                    .Select(x => ParseSwap(gameState, player, notation, x.Trim()))
+                    .ToList());
+
+            if (!match.Success)
+                throw new IllimatException(IllimatError.InvalidNotation, $"'{notation}' is not a move.");
+
+            var code = char.ToUpperInvariant(match.Groups["code"].Value[0]);
+            var left = match.Groups["left"].Success ? match.Groups["left"].Value : null;
+            var right = match.Groups["right"].Success ? match.Groups["right"].Value : null;
+            var across = match.Groups["across"].Success ? match.Groups["across"].Value : null;
+            var to = match.Groups["to"].Success ? match.Groups["to"].Value : null;
+            var field = ParseField(gameState, match.Groups["field"].Value);
+            var expectsRight = code is 'H' or 'P' or 'C' or 'R';
+            var takesOneCard = code is 'P' or 'C' or 'X' or 'R';
+
+            if ((left == null) != (code == 'L') || (right != null) != expectsRight || (across != null && code != 'H') ||
+                (to != null) != (code == 'R') || match.Groups["lock"].Success || (takesOneCard && left!.Contains(',')) ||
+                (code is 'C' or 'R' && right!.Contains(',')))
+                throw new IllimatException(IllimatError.InvalidNotation, $"'{notation}' doesn't fit the form of its move.");
+
+            return code switch
+            {
+                'S' => new SowCards(player, ParseCards(player.Hand, left!), field),
+                'R' => new RakeSow(player, ParseHandCard(player.Hand, left!), field, ParsePiles(field, right!).Single(),
+                    ParseField(gameState, to!)),
+                'H' => new HarvestCards(player, ParseCards(player.Hand, left!), field, ParsePiles(field, right!))
+                {
+                    OppositePiles = across == null ? new List<Pile>() : ParsePiles(gameState.OppositeField(field), across)
+                },
+                'P' => new StockpileCards(player, ParseHandCard(player.Hand, left!), field, ParsePiles(field, right!)),
+                'C' => new ChangelingExchange(player, field, ParseHandCard(player.Hand, left!), ParseFieldCard(field, right!)),
+                'X' => new ScatterStockpile(player, field, ParsePiles(field, left!).Single()),
+                _ => new CollectLuminary(player, field)
+            };
+        }
+
+        // Revealing a luminary and turning the Illimat can be done by the game or by a player, so neither needs a hand.
+        private static IAction ParseIllimatMove(GameState gameState, IActor actor, string notation, Match match)
+        {
+            var code = char.ToUpperInvariant(match.Groups["code"].Value[0]);
+            var field = ParseField(gameState, match.Groups["field"].Value);
+            var to = match.Groups["to"].Success ? match.Groups["to"].Value : null;
+
+            if (match.Groups["left"].Success || match.Groups["right"].Success || match.Groups["across"].Success ||
+                (to != null) != (code == 'T') || (match.Groups["lock"].Success && code != 'T'))
+                throw new IllimatException(IllimatError.InvalidNotation, $"'{notation}' doesn't fit the form of its move.");
+
+            if (code == 'V') return new RevealLuminary(actor, field);
+
+            if (!Enum.TryParse<Season>(to, true, out var season))
+                throw new IllimatException(IllimatError.InvalidNotation, $"'{to}' is not a season.");
+
+            return new ChangeSeason(actor, season, Array.IndexOf(gameState.Fields, field), match.Groups["lock"].Success);
+        }
+
+        private static string ToNotation(Card card) => $"{card.Rank.ToShortString()}{card.Suit.ToShortString()}";
+
+        private static string ToNotation(IEnumerable<Card> cards) => string.Join(",", cards.Select(x => ToNotation(x)));
+
+        private static string ToNotation(Pile pile) => string.Join("+", pile.Cards.Select(x => ToNotation(x)));
+
+        private static string ToNotation(IEnumerable<Pile> piles) => string.Join(",", piles.Select(x => ToNotation(x)));
+
+        private static Field ParseField(GameState gameState, string fieldString)
+        {
+            if (int.TryParse(fieldString, out var index))
+                return gameState.FieldAt(index) ??
+                    throw new IllimatException(IllimatError.InvalidField, $"'{fieldString}' doesn't name a field.");
+            if (Enum.TryParse<Season>(fieldString, true, out var season))
+                return gameState.FieldIn(season);
+
+            throw new IllimatException(IllimatError.InvalidField, $"'{fieldString}' doesn't name a field.");
+        }
+
+        private static Player? ParseVictim(GameState gameState, Player player, string name)
+        {
+            if (name.Equals(ILLIMAT_NAME, StringComparison.OrdinalIgnoreCase)) return null;
+
+            return gameState.Players.FirstOrDefault(x => !ReferenceEquals(x, player) &&
+                    x.Name.Equals(name, StringComparison.OrdinalIgnoreCase)) ??
+                throw new IllimatException(IllimatError.InvalidTarget, $"'{name}' isn't another player or the Illimat.");
+        }
+
+        private static Okus ParseIllimatOkus(GameState gameState, string description) =>
+            gameState.IllimatOkuses.FirstOrDefault(x => x.Description.Equals(description, StringComparison.OrdinalIgnoreCase)) ??
+                throw new IllimatException(IllimatError.NoOkus, $"No okus on the Illimat is called '{description}'.");
+
+        private static List<Card> ParseCards(IEnumerable<Card> hand, string cardsString)
+        {
+            var remaining = hand.ToList();
+            var cards = new List<Card>();
+
+            foreach (var cardString in cardsString.Split(','))
+            {
+                var card = ParseHandCard(remaining, cardString);
+                remaining.Remove(card);
+                cards.Add(card);
+            }
+
+            return cards;
+        }
+
+        // One exchange of a claim exchange move: a card in hand for a loose card in the named field.
+        private static ExchangeCard ParseSwap(GameState gameState, Player player, string notation, string swap)
+        {
+            var match = SwapMove.Match(swap);
+
+            if (!match.Success || match.Groups["left"].Value.Contains(',') || match.Groups["right"].Value.Contains(','))
+                throw new IllimatException(IllimatError.InvalidNotation, $"'{notation}' doesn't fit the form of its move.");
+
+            var field = ParseField(gameState, match.Groups["field"].Value);
+
+            return new ExchangeCard(player, field, ParseHandCard(player.Hand, match.Groups["left"].Value),
+                ParseFieldCard(field, match.Groups["right"].Value));
+        }
+
+        private static Card ParseHandCard(IEnumerable<Card> hand, string cardString) =>
+            ParseCard(hand, cardString, "hand", IllimatError.CardNotInHand);
+
+        private static Card ParseFieldCard(Field field, string cardString) =>
+            ParseCard(field.Piles.SelectMany(x => x.Cards), cardString, $"field {field}", IllimatError.InvalidField);
+
+        // Resolves a card reference to the one card among the candidates it could mean, so the action holds the very
+        // card in play rather than an equal copy.
+        private static Card ParseCard(IEnumerable<Card> candidates, string cardString, string location, IllimatError missing)
+        {
+            var (rank, suit) = ParseCardReference(cardString);
+            var matches = candidates.Where(x => x.Rank == rank && (suit == null || x.Suit == suit)).ToList();
+
+            return matches.Count switch
+            {
+                0 => throw new IllimatException(missing, $"No card in {location} matches '{cardString}'."),
+                1 => matches[0],
+                _ => throw new IllimatException(IllimatError.AmbiguousCard, $"'{cardString}' could mean any of {ToNotation(matches)}.")
+            };
+        }
+
+        private static List<Pile> ParsePiles(Field field, string pilesString)
+        {
+            var piles = new List<Pile>();
+
+            foreach (var pileString in pilesString.Split(','))
+            {
+                var references = pileString.Split('+').Select(ParseCardReference).ToList();
+                var matches = field.Piles.Where(x => !piles.Contains(x) && PileMatches(x, references)).ToList();
+
+                piles.Add(matches.Count switch
+                {
+                    0 => throw new IllimatException(IllimatError.InvalidField, $"No pile in field {field} matches '{pileString}'."),
+                    1 => matches[0],
+                    _ => throw new IllimatException(IllimatError.AmbiguousCard, $"'{pileString}' could mean any of {ToNotation(matches)}.")
+                });
+            }
+
+            return piles;
+        }
+
+        private static bool PileMatches(Pile pile, List<(Rank Rank, Suit? Suit)> references)
+        {
+            if (pile.Cards.Count != references.Count) return false;
+
+            var remaining = pile.Cards.ToList();
+
+            foreach (var (rank, suit) in references)
+            {
+                var card = remaining.FirstOrDefault(x => x.Rank == rank && (suit == null || x.Suit == suit));
+                if (card == null) return false;
+                remaining.Remove(card);
+            }
+
+            return true;
+        }
+
+        // A bare rank leaves the suit open; otherwise the suit is whatever follows the rank.
+        private static (Rank Rank, Suit? Suit) ParseCardReference(string cardString)
+        {
+            if (TryParse<Rank>(cardString.ToRank, out var bareRank)) return (bareRank, null);
+
+            for (int i = 1; i < cardString.Length; i++)
+            {
+                if (TryParse<Rank>(cardString[..i].ToRank, out var rank) && TryParse<Suit>(cardString[i..].ToSuit, out var suit))
+                    return (rank, suit);
+            }
+
+            throw new IllimatException(IllimatError.InvalidNotation, $"'{cardString}' is not a card.");
+        }
+
+        private static bool TryParse<T>(Func<T> parse, out T result)
+        {
+            try
+            {
+                result = parse();
+                return true;
+            }
+            catch (ArgumentException)
+            {
+                result = default!;
+                return false;
+            }
+        }
+    }
+}
@@ -58,6 +58,8 @@ namespace Illimat.Core.Extensions
             _ => throw new ArgumentException($"Rank '{(int)rank}' is undefined.")
         };
 
+        public static bool IsFaceCard(this Rank rank) => rank is Rank.Knight or Rank.Queen or Rank.King;
+
         public static Rank ToRank(this string rankString)
         {
             return rankString.ToLowerInvariant() switch
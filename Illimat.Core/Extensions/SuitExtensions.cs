@@ -34,6 +34,16 @@ namespace Illimat.Core.Extensions
             _ => throw new ArgumentException($"Suit '{suit}' is undefined.")
         };
 
+        public static Season? ToSeason(this Suit suit) => suit switch
+        {
+            Suit.Spring => Season.Spring,
+            Suit.Summer => Season.Summer,
+            Suit.Autumn => Season.Autumn,
+            Suit.Winter => Season.Winter,
+            Suit.Stars => null,
+            _ => throw new ArgumentException($"Suit '{suit}' is undefined.")
+        };
+
         public static Suit ToSuit(this string suitString) => suitString.ToLowerInvariant() switch
         {
             "spring" or "sp" => Suit.Spring,
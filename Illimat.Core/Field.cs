@@ -7,6 +7,8 @@ namespace Illimat.Core
     {
         public List<Pile> Piles { get; init; } = new List<Pile>();
         public Luminary? Luminary { get; set; }
+        // Face-down cards held by luminaries like the Soldiers or the Children, in the order they were placed.
+        public List<Card> HiddenCards { get; } = new();
         public Season Season { get; set; }
         public bool IgnoreSeason { get; set; } = false;
         public bool IgnoreField { get; set; } = false;
@@ -0,0 +1,25 @@
+﻿namespace Illimat.Core
+{
+    public record class FieldCapabilities
+    {
+        public bool CanSow { get; init; }
+        public bool CanHarvest { get; init; }
+        public bool CanStockpile { get; init; }
+        // One line per luminary that changed what the field's season alone would allow, in the order applied.
+        public IReadOnlyList<string> SpecialRules { get; init; }
+
+        public FieldCapabilities(bool canSow, bool canHarvest, bool canStockpile, IReadOnlyList<string>? specialRules = null)
+        {
+            CanSow = canSow;
+            CanHarvest = canHarvest;
+            CanStockpile = canStockpile;
+            SpecialRules = specialRules ?? new List<string>();
+        }
+
+        public virtual bool Equals(FieldCapabilities? other) => other is not null &&
+            CanSow == other.CanSow && CanHarvest == other.CanHarvest && CanStockpile == other.CanStockpile &&
+            SpecialRules.SequenceEqual(other.SpecialRules);
+
+        public override int GetHashCode() => HashCode.Combine(CanSow, CanHarvest, CanStockpile, SpecialRules.Count);
+    }
+}
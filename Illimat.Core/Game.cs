@@ -1,4 +1,5 @@
-﻿using Illimat.Core.Models;
+﻿using Illimat.Core.Actions;
+using Illimat.Core.Models;
 
 namespace Illimat.Core
 {
@@ -6,26 +7,225 @@ namespace Illimat.Core
     {
         public string Name { get; } = "Game";
         public Dictionary<PlayerType, int> PlayerCounts { get; } = new() { { PlayerType.Human, 0 }, { PlayerType.Computer, 0 } };
-        public Random Random { get; }
+        public GameConfig Config { get; }
+        public GameRandom Random { get; private set; }
+        // Kept even when the seed was chosen from the clock, so any game can be rebuilt card for card.
+        public int Seed => Random.Seed;
         public GameState? State { get; set; }
         public Queue<IAction> PendingActions = new();
         public Stack<IAction> CompletedActions = new();
+        public event Action<GameEvent>? EventRaised;
+        private readonly Dictionary<string, int> boardRepetitions = new();
+        // The repetitions each scored round cleared away, so unwinding the scoring brings them back.
+        private readonly Stack<Dictionary<string, int>> repetitionsBeforeScoring = new();
+        private readonly List<string> replayMoves = new();
+        // Every completed action as a replay move, noted before it was performed so fields are named as they stood.
+        public IReadOnlyList<string> ReplayMoves => replayMoves;
 
         const int DEFAULT_HUMAN_PLAYER_COUNT = 1;
         const int DEFAULT_COMPUTER_PLAYER_COUNT = 3;
+        const string BENCH_VARIABLE = "ILLIMAT_BENCH";
+        const int BENCH_SEED = 1;
 
-        public Game(int humanCount = DEFAULT_HUMAN_PLAYER_COUNT, int computerCount = DEFAULT_COMPUTER_PLAYER_COUNT, int? seed = null)
+        public Game(int humanCount = DEFAULT_HUMAN_PLAYER_COUNT, int computerCount = DEFAULT_COMPUTER_PLAYER_COUNT, int? seed = null, GameConfig? config = null)
         {
+            Config = config ?? new GameConfig();
             PlayerCounts[PlayerType.Human] = humanCount;
             PlayerCounts[PlayerType.Computer] = computerCount;
             seed ??= (int)DateTime.Now.Ticks;
-            Random = new Random(seed.Value);
+            Random = new GameRandom(seed.Value);
             State = new GameState(this);
 
             Console.WriteLine($"Created a new game of Illimat with {PlayerCounts[PlayerType.Human]} human and " +
                 $"{PlayerCounts[PlayerType.Computer]} computer players. Game seed: {seed}.");
         }
 
-        public static void Main() {}
+        public void Raise(GameEvent gameEvent) => EventRaised?.Invoke(gameEvent);
+
+        // Moves the random source on to where a saved game left it, as if every draw since the deal had been made here.
+        internal void RestoreRandom(long sampleCount) => Random = GameRandom.Restore(Seed, sampleCount);
+
+        public ActionOutcome Perform(IAction action)
+        {
+            var state = State ?? throw new InvalidOperationException("The game has no state to perform actions on.");
+
+            return Perform(state, action, GameReplay.ToMove(state, action));
+        }
+
+        // Takes the next queued action, such as a step of the deal, and performs it, returning null when nothing is
+        // queued. Queued actions are the only ones recorded for a replay without notation.
+        public IAction? PerformPending()
+        {
+            var state = State ?? throw new InvalidOperationException("The game has no state to perform actions on.");
+
+            if (!PendingActions.TryDequeue(out var action)) return null;
+
+            Perform(state, action, GameReplay.PENDING_MOVE);
+            return action;
+        }
+
+        private ActionOutcome Perform(GameState state, IAction action, string replayMove)
+        {
+            var orientation = state.Fields[0].Season;
+            var faceDownLuminaries = state.Fields.Select(x => x.Luminary).OfType<Luminary>().Where(x => !x.IsRevealed).ToList();
+            var events = new List<GameEvent>();
+            Action<GameEvent> collectEvent = events.Add;
+
+            EventRaised += collectEvent;
+            try
+            {
+                action.Perform(state);
+            }
+            finally
+            {
+                EventRaised -= collectEvent;
+            }
+
+            CompletedActions.Push(action);
+            replayMoves.Add(replayMove);
+
+            if (action is ScoreRound)
+            {
+                repetitionsBeforeScoring.Push(new Dictionary<string, int>(boardRepetitions));
+                boardRepetitions.Clear();
+            }
+            else
+            {
+                var boardKey = state.BoardKey();
+                boardRepetitions[boardKey] = boardRepetitions.GetValueOrDefault(boardKey) + 1;
+            }
+
+            return new ActionOutcome(
+                events.Any(x => x.Type == GameEventType.FieldCleared),
+                state.Fields[0].Season == orientation ? null : (orientation, state.Fields[0].Season),
+                faceDownLuminaries.Where(x => x.IsRevealed).ToList(),
+                events);
+        }
+
+        // Ends the active player's turn: they draw back up to a full hand and play passes to the next player holding
+        // cards. Once-a-turn abilities such as the Loom and the Changeling are free again under the new turn number.
+        // When nobody has a card left to play, the round's scoring is queued instead and false is returned.
+        public bool AdvanceTurn()
+        {
+            var state = State ?? throw new InvalidOperationException("The game has no state to advance.");
+
+            Perform(new DrawUp(state.ActivePlayer));
+
+            for (int i = 1; i <= state.Players.Count; i++)
+            {
+                var nextPlayer = state.Players[(state.ActivePlayerIndex + i) % state.Players.Count];
+                if (nextPlayer.Hand.Count == 0) continue;
+
+                Perform(new BeginTurn(nextPlayer));
+                return true;
+            }
+
+            PendingActions.Enqueue(new ScoreRound(this));
+            return false;
+        }
+
+        // Unwinds every action taken since the active player's turn began, so a multi-step turn can be taken back
+        // as a whole.
+        public void RestartTurn()
+        {
+            var state = State ?? throw new InvalidOperationException("The game has no state to restart a turn in.");
+
+            if (!CompletedActions.OfType<BeginTurn>().Any())
+                throw new InvalidOperationException("No turn has begun yet.");
+
+            while (CompletedActions.Peek() is not BeginTurn)
+            {
+                Unwind(state, CompletedActions.Pop());
+            }
+        }
+
+        // Takes back the most recent action alone. Every action records what it changed, so nothing is cloned and deck
+        // draws go back on top of the deck. An action that was taken from the pending actions goes back to the front of
+        // the queue, to be performed next again.
+        public void UndoLast()
+        {
+            var state = State ?? throw new InvalidOperationException("The game has no state to undo an action in.");
+
+            if (CompletedActions.Count == 0)
+                throw new InvalidOperationException("No action has been performed yet.");
+
+            Unwind(state, CompletedActions.Pop());
+        }
+
+        private void Unwind(GameState state, IAction action)
+        {
+            if (action is ScoreRound)
+            {
+                boardRepetitions.Clear();
+                foreach (var (key, count) in repetitionsBeforeScoring.Pop()) boardRepetitions[key] = count;
+            }
+            else
+            {
+                var boardKey = state.BoardKey();
+
+                if (boardRepetitions.TryGetValue(boardKey, out var count))
+                {
+                    if (count > 1) boardRepetitions[boardKey] = count - 1;
+                    else boardRepetitions.Remove(boardKey);
+                }
+            }
+
+            action.Unwind(state);
+
+            if (replayMoves[^1] == GameReplay.PENDING_MOVE)
+                PendingActions = new Queue<IAction>(PendingActions.Prepend(action));
+            replayMoves.RemoveAt(replayMoves.Count - 1);
+        }
+
+        // How many times this round the current board has been reached after an action, so that a UI or
+        // tournament harness can break a cycle that makes no progress.
+        public int RepetitionCount()
+        {
+            var state = State ?? throw new InvalidOperationException("The game has no state to inspect.");
+
+            return boardRepetitions.GetValueOrDefault(state.BoardKey());
+        }
+
+        // Performs an action submitted on behalf of a player, e.g. over the network, rejecting it if it isn't their turn.
+        public ActionOutcome PerformFor(Player player, IAction action)
+        {
+            var state = State ?? throw new InvalidOperationException("The game has no state to perform actions on.");
+            var activePlayer = state.ActivePlayer;
+
+            if (!ReferenceEquals(player, activePlayer))
+                throw new IllimatException(IllimatError.NotYourTurn, $"It is not {player.Name}'s turn; {activePlayer.Name} is the active player.");
+            if (!ReferenceEquals(action.Actor, player))
+                throw new IllimatException(IllimatError.WrongActor, $"{player.Name} cannot submit an action performed by {action.Actor.Name}.");
+
+            return Perform(action);
+        }
+
+        // With ILLIMAT_BENCH set to a number of rounds, plays that many seeded rounds between computer players without
+        // prompting, each the first round of a fresh game, then prints each seat's per-round win rate and the average
+        // round length. The games' own logging is muted.
+        public static void Main()
+        {
+            if (!int.TryParse(Environment.GetEnvironmentVariable(BENCH_VARIABLE), out var count) || count <= 0) return;
+
+            var output = Console.Out;
+            PlayoutStats stats;
+
+            Console.SetOut(TextWriter.Null);
+            try
+            {
+                stats = Playout.RunCpuPlayouts(count, BENCH_SEED);
+            }
+            finally
+            {
+                Console.SetOut(output);
+            }
+
+            Console.WriteLine($"Played {stats.Rounds} rounds from seed {BENCH_SEED}.");
+            for (int i = 0; i < stats.WinRates.Count; i++)
+            {
+                Console.WriteLine($"Seat {i}: won {stats.WinRates[i]:P1} of rounds, averaging {stats.AverageScores[i]:F2} points a round.");
+            }
+            Console.WriteLine($"Average round length: {stats.AverageTurns:F1} turns.");
+        }
     }
 }
@@ -0,0 +1,21 @@
+﻿using Illimat.Core.Models;
+
+namespace Illimat.Core
+{
+    public record class GameConfig
+    {
+        public bool UseLuminaries { get; init; } = true;
+        public LuminaryRevealMode LuminaryRevealMode { get; init; } = LuminaryRevealMode.OnFieldClear;
+        public HarvestSuitRule HarvestSuitRule { get; init; } = HarvestSuitRule.Any;
+        // Index of the player who deals the first round; play starts to their left. Defaults to the first player.
+        public int? StartingDealer { get; init; }
+        // The score that ends the game once a round has been scored; lower it for house rules or quick games.
+        public int VictoryThreshold { get; init; } = 17;
+        // The luminaries the game is played with, in the order they are dealt. Null plays with every luminary.
+        public IReadOnlyList<LuminaryName>? Luminaries { get; init; }
+
+        // Chooses luminaries a whole set at a time, e.g. the base game plus The Crane Wife.
+        public GameConfig WithLuminaries(params IEnumerable<LuminaryName>[] luminarySets) =>
+            this with { Luminaries = luminarySets.SelectMany(x => x).ToList() };
+    }
+}
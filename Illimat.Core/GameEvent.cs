@@ -0,0 +1,18 @@
+﻿using Illimat.Core.Models;
+
+namespace Illimat.Core
+{
+    public record class GameEvent
+    {
+        public GameEventType Type { get; init; }
+        public IActor Actor { get; init; }
+        public string Description { get; init; }
+
+        public GameEvent(GameEventType type, IActor actor, string description)
+        {
+            Type = type;
+            Actor = actor;
+            Description = description;
+        }
+    }
+}
@@ -0,0 +1,52 @@
+﻿namespace Illimat.Core
+{
+    // A seeded Random that counts the samples it has produced. Saving the seed and sample count is enough to restore
+    // it to exactly the same position, so a resumed game shuffles and draws as if it had never been interrupted.
+    public class GameRandom : Random
+    {
+        public int Seed { get; }
+        public long SampleCount { get; private set; }
+
+        public GameRandom(int seed) : base(seed)
+        {
+            Seed = seed;
+        }
+
+        public static GameRandom Restore(int seed, long sampleCount)
+        {
+            var random = new GameRandom(seed);
+
+            while (random.SampleCount < sampleCount)
+            {
+                random.Sample();
+            }
+
+            return random;
+        }
+
+        protected override double Sample()
+        {
+            SampleCount++;
+            return base.Sample();
+        }
+
+        // Random only routes some overloads through Sample, so the rest are redirected here to keep every draw counted.
+        public override int Next() => Next(int.MaxValue);
+
+        public override int Next(int minValue, int maxValue)
+        {
+            if (minValue > maxValue)
+                throw new ArgumentOutOfRangeException(nameof(minValue), $"{nameof(minValue)} must not exceed {nameof(maxValue)}.");
+
+            return (int)(minValue + (long)(Sample() * ((long)maxValue - minValue)));
+        }
+
+        public override void NextBytes(byte[] buffer)
+        {
+            for (int i = 0; i < buffer.Length; i++)
+            {
+                buffer[i] = (byte)Next(256);
+            }
+        }
+    }
+}
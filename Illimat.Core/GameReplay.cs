@@ -0,0 +1,110 @@
+﻿using Illimat.Core.Extensions;
+using Illimat.Core.Models;
+using System.Text.Json;
+
+namespace Illimat.Core
+{
+    // Everything needed to play a game back: how it was set up, then each completed action in order. Player moves are
+    // kept in move notation, prefixed with the index of the player who made them, and moves the game made itself are
+    // prefixed with "G" instead. "~" stands for whatever action the game had queued next, such as the deal, which the
+    // same seed will queue again.
+    public record class GameReplay
+    {
+        public const string PENDING_MOVE = "~";
+        public const string GAME_ACTOR = "G";
+        // Bump whenever the saved form changes, and teach Migrate how to bring the older form up to date.
+        public const int CURRENT_SCHEMA_VERSION = 3;
+
+        // Replays saved before versioning have no version, and read as version 1.
+        public int SchemaVersion { get; init; } = 1;
+        public int Seed { get; init; }
+        public int HumanCount { get; init; }
+        public int ComputerCount { get; init; }
+        public GameConfig Config { get; init; } = new();
+        public List<string> Moves { get; init; } = new();
+        // How far the game's random source had been drawn when it was saved, so a resumed game carries on drawing
+        // from the same point. Zero leaves it wherever replaying the moves puts it.
+        public long SampleCount { get; init; }
+
+        public static GameReplay Record(Game game) => new()
+        {
+            SchemaVersion = CURRENT_SCHEMA_VERSION,
+            Seed = game.Seed,
+            HumanCount = game.PlayerCounts[PlayerType.Human],
+            ComputerCount = game.PlayerCounts[PlayerType.Computer],
+            Config = game.Config,
+            Moves = game.ReplayMoves.ToList(),
+            SampleCount = game.Random.SampleCount
+        };
+
+        // Only actions taken from the pending queue may go without notation, since the replay can't make them again
+        // any other way.
+        public static string ToMove(GameState gameState, IAction action)
+        {
+            if (!action.HasNotation())
+                throw new ArgumentException($"{action.GetType().Name} has no notation, so it can only be performed from the pending actions.");
+
+            var actor = action.Actor is Player player ? gameState.Players.IndexOf(player).ToString() : GAME_ACTOR;
+            return $"{actor} {action.ToNotation()}";
+        }
+
+        public static GameReplay FromJson(string json) =>
+            Migrate(JsonSerializer.Deserialize<GameReplay>(json) ?? throw new ArgumentException("The replay is empty."));
+
+        // Fields added since a replay was saved take their defaults when it is read, so an older replay only needs
+        // its version brought up to date. A replay from a newer version can't be trusted to mean the same thing.
+        private static GameReplay Migrate(GameReplay replay)
+        {
+            if (replay.SchemaVersion > CURRENT_SCHEMA_VERSION)
+                throw new ArgumentException($"The replay uses schema version {replay.SchemaVersion}, but only versions up to {CURRENT_SCHEMA_VERSION} can be read.");
+
+            // Version 1 predates VictoryThreshold and Luminaries in the config, which default to 17 and every luminary.
+            // Versions 1 and 2 predate SampleCount, which reads as zero.
+            return replay with { SchemaVersion = CURRENT_SCHEMA_VERSION };
+        }
+
+        public string ToJson() => JsonSerializer.Serialize(this);
+
+        // Builds a fresh game from the seed and performs every move again, ending in the same position as the
+        // recorded game.
+        public Game Replay()
+        {
+            var game = new Game(HumanCount, ComputerCount, Seed, Config);
+            var state = game.State!;
+
+            foreach (var move in Moves)
+            {
+                if (move == PENDING_MOVE)
+                {
+                    if (game.PerformPending() == null)
+                        throw new IllimatException(IllimatError.InvalidNotation, "The replay expects a queued action, but none is pending.");
+
+                    continue;
+                }
+
+                var parts = move.Split(' ', 2);
+                if (parts.Length == 2 && parts[0] == GAME_ACTOR)
+                {
+                    game.Perform(state.ParseGameNotation(parts[1]));
+                    continue;
+                }
+
+                if (parts.Length < 2 || !int.TryParse(parts[0], out var playerIndex) || playerIndex < 0 || playerIndex >= state.Players.Count)
+                    throw new IllimatException(IllimatError.InvalidNotation, $"'{move}' doesn't name a player and a move.");
+
+                var player = state.Players[playerIndex];
+                game.Perform(state.ParseNotation(player, parts[1]));
+            }
+
+            if (SampleCount > 0)
+            {
+                if (SampleCount < game.Random.SampleCount)
+                    throw new ArgumentException($"The replay was saved after {SampleCount} random draws, but replaying it took {game.Random.SampleCount}.");
+
+                game.RestoreRandom(SampleCount);
+            }
+
+            return game;
+        }
+    }
+}
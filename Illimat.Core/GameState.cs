@@ -7,9 +7,10 @@ namespace Illimat.Core
     public record class GameState
     {
         public Game Game { get; }
+        public LuminaryManager Luminaries { get; }
         public List<Player> Players { get; init; }
         public Deck<Card> CardDeck { get; init; }
-        public Deck<Luminary> LuminaryDeck { get; } = new Deck<Luminary>(Luminary.AllLuminaries());
+        public Deck<Luminary> LuminaryDeck { get; }
         public Field[] Fields = new Field[4] 
         { 
             new Field(Season.Spring), new Field(Season.Summer), new Field(Season.Autumn), new Field(Season.Winter) 
@@ -18,17 +19,570 @@ namespace Illimat.Core
         public List<IActor> IllimatLockers { get; } = new List<IActor>();
         public int Dealer = 0;
         public int ActivePlayerIndex = 0;
+        public int TurnNumber = 0;
+        public int? ChangelingExchangeTurn = null;
+        public int? LoomStockpileTurn = null;
+        // Who may still collect okuses for fields they cleared, how many, and on which turn; it lapses with the turn.
+        public (Player Player, int Count, int Turn)? OkusCollection = null;
+        // Who has just claimed The Perfect Crime and may steal an okus for it, and on which turn; it too lapses.
+        public (Player Player, int Count, int Turn)? PerfectCrimeTheft = null;
+        // Who has just claimed the Changeling and how many of its two claim exchanges are left, on which turn.
+        public (Player Player, int Count, int Turn)? ChangelingClaimExchanges = null;
+
+        const int MAX_STOCKPILE_VALUE = 14;
 
         public GameState(Game game)
         {
             Game = game;
+            Luminaries = new LuminaryManager(this);
 
             Players = GeneratePlayers(game.PlayerCounts, Game.Random);
             CardDeck = GenerateShuffledCardDeck(game.PlayerCounts, Game.Random);
 
-            var initialDeal = new InitialDeal(Players[0]);
+            Dealer = Game.Config.StartingDealer ?? 0;
+
+            if (Dealer < 0 || Dealer >= Players.Count)
+                throw new ArgumentException($"Starting dealer {Dealer} is not one of the {Players.Count} players.");
+
+            LuminaryDeck = GenerateLuminaryDeck(Game.Config.Luminaries);
+
+            ActivePlayerIndex = (Dealer + 1) % Players.Count;
+
+            var initialDeal = new InitialDeal(Players[Dealer]);
             initialDeal.Perform(this);
-            Game.PendingActions.Enqueue(new BeginTurn(Players[1 % Players.Count]));
+            Game.PendingActions.Enqueue(new BeginTurn(Players[ActivePlayerIndex]));
+        }
+
+        public Player ActivePlayer => Players[ActivePlayerIndex];
+
+        public IReadOnlyList<Card> CurrentHand => ActivePlayer.Hand;
+
+        // Lookups by index that return null for a player or field not in the game, rather than throwing.
+        public Player? PlayerAt(int index) => index >= 0 && index < Players.Count ? Players[index] : null;
+
+        public IReadOnlyList<Card>? HandOf(int index) => PlayerAt(index)?.Hand;
+
+        public IReadOnlyList<Card>? HarvestOf(int index) => PlayerAt(index)?.HarvestPile;
+
+        public Field? FieldAt(int index) => index >= 0 && index < Fields.Length ? Fields[index] : null;
+
+        // Every season is always showing in exactly one field.
+        public Field FieldIn(Season season) => Fields.Single(x => x.Season == season);
+
+        // Hand sizes are public information, listed in player order.
+        public int[] HandSizes() => Players.Select(x => x.Hand.Count).ToArray();
+
+        // Every card in the game wherever it sits: the deck, hands, harvests, field piles and the cards hidden beneath
+        // luminaries.
+        public IEnumerable<Card> AllCardsInPlay() => CardDeck.Cards
+            .Concat(Players.SelectMany(x => x.Hand.Concat(x.HarvestPile)))
+            .Concat(Fields.SelectMany(x => x.Piles.SelectMany(pile => pile.Cards).Concat(x.HiddenCards)));
+
+        // Throws if any card has been lost or duplicated, comparing what's in play against a full deck for this many
+        // players. Meant for debugging actions and their unwinds.
+        public void AssertCardConservation()
+        {
+            var expected = Card.GetCards(SuitSetFor(Players.Count)).ToList();
+            var actual = AllCardsInPlay().ToList();
+            var missing = expected.Where(x => !actual.Remove(x)).ToList();
+
+            if (missing.Count > 0 || actual.Count > 0)
+                throw new InvalidOperationException("Cards are not conserved: " +
+                    $"missing [{string.Join(", ", missing.Select(x => x.ToFriendlyString()))}], " +
+                    $"extra [{string.Join(", ", actual.Select(x => x.ToFriendlyString()))}].");
+        }
+
+        // A face-up Island cuts its field off from play entirely.
+        public bool IsIsolated(Field field) => field.IgnoreField ||
+            (field.Luminary != null && field.Luminary.IsRevealed && field.Luminary.LuminaryName == LuminaryName.TheIsland);
+
+        // A face-up Boat in Winter is iced in, and its field can't be harvested.
+        public bool IsIcebound(Field field) => field.Season == Season.Winter &&
+            field.Luminary is { IsRevealed: true, LuminaryName: LuminaryName.TheBoat };
+
+        // A face-up Boat in Summer lets a harvest from its field take piles from the opposite field too.
+        public bool CanHarvestAcross(Field field) => field.Season == Season.Summer &&
+            field.Luminary is { IsRevealed: true, LuminaryName: LuminaryName.TheBoat };
+
+        // Fields face each other across the Illimat: Spring's field opposite Autumn's, Summer's opposite Winter's.
+        public Field OppositeField(Field field)
+        {
+            var index = Array.IndexOf(Fields, field);
+            if (index < 0) throw new ArgumentException($"Field {field} is not in play.", nameof(field));
+
+            return Fields[(index + 2) % Fields.Length];
+        }
+
+        // The two fields on either side of a field around the Illimat.
+        public IEnumerable<Field> AdjacentFields(Field field)
+        {
+            var index = Array.IndexOf(Fields, field);
+            if (index < 0) throw new ArgumentException($"Field {field} is not in play.", nameof(field));
+
+            return new[] { Fields[(index + Fields.Length - 1) % Fields.Length], Fields[(index + 1) % Fields.Length] };
+        }
+
+        public Season EffectiveSeason(Field field) => Luminaries.EffectiveSeason(field);
+
+        public bool CanSow(Field field) => Luminaries.EffectiveCapabilities(field).CanSow;
+
+        public bool CanHarvest(Field field) => Luminaries.EffectiveCapabilities(field).CanHarvest;
+
+        public bool CanStockpile(Field field) => Luminaries.EffectiveCapabilities(field).CanStockpile;
+
+        // Whether this player in particular may stockpile in the field, counting a Loom they can still use this turn.
+        public bool CanStockpile(Field field, Player player) =>
+            CanStockpile(field) || (HasLoom(player, field) && LoomStockpileTurn != TurnNumber);
+
+        // The face-up luminary, if any, that would make a sow, harvest or stockpile be rejected where the seasons alone
+        // would allow it.
+        public Luminary? BlockingLuminary(IAction action)
+        {
+            var field = action.TargetField();
+
+            if (field == null || action is not (SowCards or HarvestCards or StockpileCards)) return null;
+            if (field.Luminary is { IsRevealed: true, LuminaryName: LuminaryName.TheIsland }) return field.Luminary;
+            if (action is HarvestCards && IsIcebound(field)) return field.Luminary;
+
+            return null;
+        }
+
+        // Every field's capabilities in field order, with seasons and luminary effects already folded in.
+        public FieldCapabilities[] GetFieldCapabilities() => Fields.Select(Luminaries.EffectiveCapabilities).ToArray();
+
+        // A face-up Union lets two cards from hand be played together, harvesting with their combined value.
+        public int GetHarvestCardMax(Field field) =>
+            field.Luminary is { IsRevealed: true, LuminaryName: LuminaryName.TheUnion } ?
+                Math.Max(2, field.HarvestCardMax) :
+                field.HarvestCardMax;
+
+        // The Loom, face up in the field or claimed by the player, lets them stockpile regardless of season.
+        public bool HasLoom(Player player, Field field) =>
+            field.Luminary is { IsRevealed: true, LuminaryName: LuminaryName.TheLoom } ||
+            player.ScorePileLuminaries.Any(x => x.LuminaryName == LuminaryName.TheLoom);
+
+        public bool IsLuminaryFaceUp(LuminaryName luminaryName) =>
+            Fields.Any(x => x.Luminary != null && x.Luminary.IsRevealed && x.Luminary.LuminaryName == luminaryName);
+
+        public bool IsLuminaryClaimed(LuminaryName luminaryName) =>
+            Players.Any(x => x.ScorePileLuminaries.Any(luminary => luminary.LuminaryName == luminaryName));
+
+        // Stops at the first card in hand, or pair of cards where the Union allows it, that could harvest every pile in
+        // the field, whether from the field itself or carried across from the opposite field by a Boat.
+        public bool CanClearField(Player player, Field field)
+        {
+            if (field.Piles.Count == 0) return false;
+
+            var opposite = OppositeField(field);
+
+            return HarvestHands(player, field).Any(cards => ClearingValues(field, cards).Any()) ||
+                (CanHarvestAcross(opposite) && HarvestHands(player, opposite).Any(cards => ClearingValues(field, cards)
+                    .Any(value => HarvestablePiles(opposite, cards).GetSubsets().Any(x => x.Count > 0 && Pile.CanPartition(x, value)))));
+        }
+
+        // A stockpile can't be harvested on the turn it was made, so it only becomes a target from the next turn on.
+        public List<Pile> HarvestablePiles(Field field) =>
+            field.Piles.Where(x => x.CreatedTurn != TurnNumber).ToList();
+
+        // The piles these cards in particular could harvest; under the MatchSuit rule every harvested card must share a
+        // suit with one of them.
+        private List<Pile> HarvestablePiles(Field field, IList<Card> cards) => HarvestablePiles(field)
+            .Where(pile => Game.Config.HarvestSuitRule != HarvestSuitRule.MatchSuit ||
+                pile.Cards.All(x => cards.Any(card => card.Suit == x.Suit)))
+            .ToList();
+
+        // Each card in hand, then each pair of cards where the Union lets two be played together.
+        private IEnumerable<List<Card>> HarvestHands(Player player, Field field)
+        {
+            if (!CanHarvest(field)) yield break;
+
+            foreach (var card in player.Hand) yield return new List<Card> { card };
+
+            if (GetHarvestCardMax(field) < 2) yield break;
+
+            for (int i = 0; i < player.Hand.Count; i++)
+            {
+                for (int j = i + 1; j < player.Hand.Count; j++)
+                {
+                    yield return new List<Card> { player.Hand[i], player.Hand[j] };
+                }
+            }
+        }
+
+        // The values with which the cards would take every pile in the field.
+        private IEnumerable<int> ClearingValues(Field field, List<Card> cards)
+        {
+            if (HarvestablePiles(field, cards).Count < field.Piles.Count) return Enumerable.Empty<int>();
+
+            return Pile.SumValues(cards.Select(x => new Pile(new List<Card> { x })))
+                .Where(value => Pile.CanPartition(field.Piles, value));
+        }
+
+        // Players can't pass, so a turn needs at least one sow, harvest or stockpile to be possible. Each card is judged
+        // by LegalActions, so the Loom, the Union and the Boat count; a Changeling exchange doesn't take the turn.
+        public bool HasLegalMove(Player player) => LegalActions(player).Any(x => x is not ChangelingExchange);
+
+        public string? WhyNoMove(Player player)
+        {
+            if (HasLegalMove(player)) return null;
+
+            if (player.Hand.Count == 0)
+                return $"{player.Name} has no cards in hand, so their turn is skipped until they draw again.";
+
+            return $"{player.Name} can't sow, harvest or stockpile with any card in hand, in any field.";
+        }
+
+        // Every reason the player couldn't make the move right now, or null if nothing stands in the way. Sows, harvests
+        // and stockpiles are checked rule by rule, the same rules their actions enforce, so one call can report several
+        // problems at once.
+        public List<string>? ExplainIllegal(Player player, IAction action)
+        {
+            var reasons = new List<string>();
+
+            if (!ReferenceEquals(player, ActivePlayer))
+                reasons.Add($"It is not {player.Name}'s turn; {ActivePlayer.Name} is the active player.");
+            if (!ReferenceEquals(action.Actor, player))
+                reasons.Add($"{player.Name} cannot make a move performed by {action.Actor.Name}.");
+
+            var field = action.TargetField();
+            var blockingLuminary = BlockingLuminary(action);
+            var blockedBy = blockingLuminary == null ? "" : $" because of {blockingLuminary.Name}";
+
+            switch (action)
+            {
+                case SowCards sow:
+                    if (sow.Cards.Count == 0)
+                        reasons.Add("At least one card must be sown; passing isn't allowed.");
+                    if (!CanSow(sow.Field))
+                        reasons.Add($"Cards cannot be sown in field {Array.IndexOf(Fields, sow.Field)} during {sow.Field.Season}{blockedBy}.");
+                    if (sow.Cards.Any(x => !player.Hand.Contains(x)))
+                        reasons.Add($"Not every card to sow is in {player.Name}'s hand.");
+                    break;
+
+                case HarvestCards harvest:
+                    var harvestPiles = harvest.Piles.Concat(harvest.OppositePiles).ToList();
+                    var harvestCardMax = GetHarvestCardMax(harvest.Field);
+                    if (!CanHarvest(harvest.Field))
+                        reasons.Add($"Cards cannot be harvested from field {Array.IndexOf(Fields, harvest.Field)} during {harvest.Field.Season}{blockedBy}.");
+                    if (harvest.Cards.Count == 0 || harvest.Cards.Count > harvestCardMax)
+                        reasons.Add($"A harvest here takes between 1 and {harvestCardMax} cards from hand.");
+                    if (harvest.Cards.Any(x => !player.Hand.Contains(x)))
+                        reasons.Add($"Not every card to harvest with is in {player.Name}'s hand.");
+                    if (harvest.Piles.Count == 0 || harvest.Piles.Any(x => !harvest.Field.Piles.Contains(x)))
+                        reasons.Add($"Every pile to harvest must be in field {Array.IndexOf(Fields, harvest.Field)}.");
+                    if (harvest.OppositePiles.Count > 0 && !CanHarvestAcross(harvest.Field))
+                        reasons.Add("Only a face-up Boat in a Summer field can carry a harvest across to the opposite field.");
+                    if (harvestPiles.Any(x => x.CreatedTurn == TurnNumber))
+                        reasons.Add("A stockpile can't be harvested on the turn it was made.");
+                    if (Game.Config.HarvestSuitRule == HarvestSuitRule.MatchSuit &&
+                        harvestPiles.SelectMany(x => x.Cards).Any(x => !harvest.Cards.Any(card => card.Suit == x.Suit)))
+                        reasons.Add("Every harvested card must share a suit with a card played from hand.");
+
+                    var harvestValues = Pile.SumValues(harvest.Cards.Select(x => new Pile(new List<Card> { x })));
+                    if (harvest.Cards.Count > 0 && harvest.Piles.Count > 0 && !harvestValues.Any(value =>
+                        Pile.CanPartition(harvest.Piles, value) && Pile.CanPartition(harvest.OppositePiles, value)))
+                        reasons.Add($"The piles can't be harvested with a value of {string.Join(" or ", harvestValues)}.");
+                    break;
+
+                case StockpileCards stockpile:
+                    if (!CanStockpile(stockpile.Field) && !HasLoom(player, stockpile.Field))
+                        reasons.Add($"Cards cannot be stockpiled in field {Array.IndexOf(Fields, stockpile.Field)} during {stockpile.Field.Season}{blockedBy}.");
+                    else if (!CanStockpile(stockpile.Field, player))
+                        reasons.Add("The Loom has already been used this turn.");
+                    if (!player.Hand.Contains(stockpile.Card))
+                        reasons.Add($"Card {stockpile.Card.ToFriendlyString()} is not in {player.Name}'s hand.");
+                    if (stockpile.Piles.Count == 0 || stockpile.Piles.Any(x => !stockpile.Field.Piles.Contains(x)))
+                        reasons.Add($"A stockpile needs at least one pile, all from field {Array.IndexOf(Fields, stockpile.Field)}.");
+
+                    var stockpileValues = Pile.SumValues(stockpile.Piles.Append(new Pile(new List<Card> { stockpile.Card })));
+                    if (!player.Hand.Where(x => !x.Equals(stockpile.Card)).Any(x => x.HarvestValues().Any(stockpileValues.Contains)))
+                        reasons.Add($"{player.Name} has no other card in hand to harvest a stockpile worth {string.Join(" or ", stockpileValues)}.");
+                    break;
+            }
+
+            if (field != null && !Fields.Contains(field))
+                reasons.Add("The move targets a field that isn't in play.");
+
+            return reasons.Count == 0 ? null : reasons;
+        }
+
+        // Every distinct set of piles the card could harvest from the field, for choosing harvest targets. Piles a Boat
+        // could carry across from the opposite field aren't included; LegalActions adds those.
+        public IList<IList<Pile>> GetHarvestOptions(Card card, Field field)
+        {
+            if (!CanHarvest(field)) return new List<IList<Pile>>();
+
+            return Pile.HarvestCombinations(HarvestablePiles(field, new List<Card> { card }), card);
+        }
+
+        // Each set of piles is offered as a harvest on its own and, while a Boat in Summer allows it, together with every
+        // set of the opposite field's piles that the same value harvests.
+        private IEnumerable<HarvestCards> HarvestMoves(Player player, List<Card> cards, Field field, IEnumerable<IList<Pile>> pileSets)
+        {
+            var values = Pile.SumValues(cards.Select(x => new Pile(new List<Card> { x })));
+            var acrossSets = CanHarvestAcross(field) ?
+                HarvestablePiles(OppositeField(field), cards).GetSubsets().Where(x => x.Count > 0).ToList() :
+                new List<IList<Pile>>();
+
+            foreach (var piles in pileSets)
+            {
+                yield return new HarvestCards(player, cards, field, piles.ToList());
+
+                foreach (var across in acrossSets.Where(x => values.Any(value => Pile.CanPartition(piles, value) && Pile.CanPartition(x, value))))
+                {
+                    yield return new HarvestCards(player, cards, field, piles.ToList()) { OppositePiles = across.ToList() };
+                }
+            }
+        }
+
+        // Every set of piles the active card could be stockpiled with, once per value the stockpile could take. Values
+        // above the highest card are never harvestable; given a player, only values another card in their hand can
+        // harvest are offered, matching what StockpileCards accepts.
+        public List<StockpileOption> StockpileOptions(Field field, Card activeCard, Player? player = null)
+        {
+            if (player == null ? !CanStockpile(field) : !CanStockpile(field, player)) return new List<StockpileOption>();
+
+            var activePile = new Pile(new List<Card> { activeCard });
+
+            return field.Piles.GetSubsets()
+                .Where(x => x.Count > 0)
+                .SelectMany(piles => Pile.SumValues(piles.Append(activePile))
+                    .Where(value => value <= MAX_STOCKPILE_VALUE)
+                    .Where(value => player == null ||
+                        player.Hand.Any(x => !x.Equals(activeCard) && x.HarvestValues().Contains(value)))
+                    .Select(value => new StockpileOption(piles.ToList(), value)))
+                .ToList();
+        }
+
+        // Every sow, harvest, stockpile and Changeling exchange the player could make right now, each ready to perform.
+        // Sowing is offered one card at a time, alongside every card a face-up Rake could rake onward, and where the
+        // Union allows it every pair of cards in hand is offered as a combined harvest. Harvests follow the suit rule
+        // and include what a Boat could carry across. Actions are listed field by field, then card by card in hand
+        // order, so the same position always gives the same list in the same order.
+        public List<IAction> LegalActions(Player player)
+        {
+            var actions = new List<IAction>();
+            var canExchange = ChangelingExchangeTurn != TurnNumber;
+
+            foreach (var field in Fields)
+            {
+                foreach (var card in player.Hand)
+                {
+                    if (CanSow(field)) actions.Add(new SowCards(player, new List<Card> { card }, field));
+
+                    if (CanSow(field) && field.Luminary is { IsRevealed: true, LuminaryName: LuminaryName.TheRake })
+                    {
+                        actions.AddRange(field.Piles.Where(x => !x.IsStockpile)
+                            .SelectMany(pile => AdjacentFields(field).Where(CanSow), (pile, destination) =>
+                                new RakeSow(player, card, field, pile, destination)));
+                    }
+
+                    actions.AddRange(HarvestMoves(player, new List<Card> { card }, field, GetHarvestOptions(card, field)));
+
+                    actions.AddRange(StockpileOptions(field, card, player)
+                        .GroupBy(x => string.Join(",", x.Piles.Select(pile => field.Piles.IndexOf(pile))))
+                        .Select(x => new StockpileCards(player, card, field, x.First().Piles.ToList())));
+
+                    var canUseChangeling = field.Luminary is { IsRevealed: true, LuminaryName: LuminaryName.TheChangeling } ||
+                        player.ScorePileLuminaries.Any(x => x.LuminaryName == LuminaryName.TheChangeling);
+                    if (canExchange && canUseChangeling)
+                    {
+                        actions.AddRange(ExchangeCandidates(field)
+                            .Select(x => new ChangelingExchange(player, field, card, x)));
+                    }
+                }
+
+                if (CanHarvest(field) && GetHarvestCardMax(field) >= 2)
+                {
+                    for (int i = 0; i < player.Hand.Count; i++)
+                    {
+                        for (int j = i + 1; j < player.Hand.Count; j++)
+                        {
+                            var cards = new List<Card> { player.Hand[i], player.Hand[j] };
+                            var values = Pile.SumValues(cards.Select(x => new Pile(new List<Card> { x })));
+
+                            actions.AddRange(HarvestMoves(player, cards, field, HarvestablePiles(field, cards).GetSubsets()
+                                .Where(x => x.Count > 0 && values.Any(value => Pile.CanPartition(x, value)))));
+                        }
+                    }
+                }
+            }
+
+            return actions;
+        }
+
+        // Only loose cards can be exchanged, e.g. by the Changeling; cards bound up in a stockpile are never offered.
+        public IEnumerable<Card> ExchangeCandidates(Field field) =>
+            field.Piles.Where(x => !x.IsStockpile).SelectMany(x => x.Cards);
+
+        // What a player could do if they held the card, judged from the public board alone. Stockpiles are left out
+        // because whether one is allowed depends on the rest of the player's hidden hand.
+        public List<IAction> HypotheticalActions(Player player, Card assumedCard)
+        {
+            var isVisible = Fields.Any(x => x.Piles.Any(pile => pile.Cards.Contains(assumedCard))) ||
+                Players.Any(x => x.HarvestPile.Contains(assumedCard));
+
+            if (isVisible)
+                throw new ArgumentException($"Card {assumedCard} is already visible, so {player.Name} can't be holding it.");
+
+            var actions = new List<IAction>();
+
+            foreach (var field in Fields)
+            {
+                if (CanSow(field)) actions.Add(new SowCards(player, new List<Card> { assumedCard }, field));
+
+                actions.AddRange(GetHarvestOptions(assumedCard, field)
+                    .Select(piles => new HarvestCards(player, assumedCard, field, piles.ToList())));
+            }
+
+            return actions;
+        }
+
+        // Tempo measures how well the current seasons suit a player: every card in their hand scores a point for each
+        // field it could be sown into, harvest something from, or be stockpiled in, and the best opponent's total is
+        // subtracted from theirs. Plays are counted from LegalActions, so a stockpile only counts where another card in
+        // the same hand could harvest it, and the Loom, the Union and the Boat count as they allow. Positive tempo means
+        // the Illimat's orientation favours the player.
+        public int Tempo(Player player)
+        {
+            var opponentPlays = Players
+                .Where(x => !ReferenceEquals(x, player))
+                .Select(CountSeasonalPlays)
+                .DefaultIfEmpty(0)
+                .Max();
+
+            return CountSeasonalPlays(player) - opponentPlays;
+        }
+
+        // Tempo as it would stand with the Illimat turned so that the field is in the season, as a face card played
+        // there would turn it, judged on the hands as they stand. The seasons are put back before returning, and while
+        // the Illimat is locked it can't turn at all.
+        public int TempoAfterTurning(Player player, Field field, Season season)
+        {
+            if (IllimatLockers.Count > 0) return Tempo(player);
+
+            var seasons = Fields.Select(x => x.Season).ToArray();
+            var index = Array.IndexOf(Fields, field);
+
+            try
+            {
+                for (int i = 0; i < Fields.Length; i++)
+                {
+                    Fields[(index + i) % Fields.Length].Season = (Season)(((int)season + i) % 4);
+                }
+
+                return Tempo(player);
+            }
+            finally
+            {
+                for (int i = 0; i < Fields.Length; i++)
+                {
+                    Fields[i].Season = seasons[i];
+                }
+            }
+        }
+
+        // Each card scores once per field for each kind of play, sow, harvest or stockpile, however many ways it has
+        // to make it. A Rake sow counts as a sow, and a Union harvest counts for both cards.
+        private int CountSeasonalPlays(Player player) => LegalActions(player)
+            .Where(x => x is not ChangelingExchange)
+            .SelectMany(action => action.PlayedCards(), (action, card) =>
+                (Kind: action is RakeSow ? typeof(SowCards) : action.GetType(), Field: action.TargetField(), Card: card))
+            .Distinct()
+            .Count();
+
+        // A canonical description of the seasons, the piles in every field, each hand and each harvest pile's size.
+        // Pile and card order is ignored so that positions reached by different routes compare equal.
+        public string BoardKey()
+        {
+            static string CardKey(Card card) => $"{(int)card.Rank}{card.Suit}";
+
+            var fields = Fields.Select(field => $"{field.Season}:" + string.Join(",", field.Piles
+                .Select(pile => string.Join("+", pile.Cards.Select(CardKey).OrderBy(x => x)))
+                .OrderBy(x => x)));
+            var players = Players.Select(player =>
+                string.Join(",", player.Hand.Select(CardKey).OrderBy(x => x)) + $"/{player.HarvestPile.Count}");
+
+            return $"{ActivePlayerIndex}|{string.Join("|", fields)}|{string.Join("|", players)}";
+        }
+
+        // Everyone sharing the highest score at or above the victory threshold. Scores only change as a round is scored,
+        // so this is settled between rounds.
+        public List<Player> Winners()
+        {
+            var contenders = Players.Where(x => x.Score >= Game.Config.VictoryThreshold).ToList();
+            if (contenders.Count == 0) return contenders;
+
+            var best = contenders.Max(x => x.Score);
+
+            return contenders.Where(x => x.Score == best).ToList();
+        }
+
+        // The player who has reached the victory threshold with the highest score. Nobody wins while the lead is
+        // tied, so play goes on for another round.
+        public Player? Winner()
+        {
+            var winners = Winners();
+
+            return winners.Count == 1 ? winners[0] : null;
+        }
+
+        // The game is over once one player leads at or above the threshold; tied leaders play on.
+        public bool IsTerminal() => Winner() != null;
+
+        // What each player would score for the round if it ended right now, source by source.
+        public ScoreBreakdown[] ScorePreview() => ScoreRound.CalculateBreakdowns(this);
+
+        // Each player's total if the round ended right now, without touching their actual scores.
+        public int[] ProvisionalScores()
+        {
+            var roundScores = ScoreRound.CalculateRoundScores(this);
+
+            return Players.Select((x, i) => x.Score + roundScores[i]).ToArray();
+        }
+
+        // A cheap, deliberately loose range for a player's round score. The floor keeps the points already banked and
+        // assumes every negative superlative lands on them; the ceiling adds every Fool, luminary and okus still in
+        // play plus every positive superlative, and Frostbit turned into points while the player holds the River or
+        // it is still there to be claimed. Neither end is necessarily reachable.
+        public (int Min, int Max) ScoreBounds(Player player)
+        {
+            var banked = player.FoolPoints + player.LuminaryPoints + player.OkusPoints;
+            var foolsInPlay = CardDeck.Cards
+                .Concat(Fields.SelectMany(x => x.Piles).SelectMany(x => x.Cards))
+                .Concat(Fields.SelectMany(x => x.HiddenCards))
+                .Concat(Players.SelectMany(x => x.Hand))
+                .Count(x => x.Rank == Rank.Fool);
+            var luminariesInPlay = Fields.Count(x => x.Luminary != null);
+            var riverInReach = player.ScorePileLuminaries.Any(x => x.LuminaryName == LuminaryName.TheRiver) ||
+                Fields.Any(x => x.Luminary?.LuminaryName == LuminaryName.TheRiver) ||
+                LuminaryDeck.Cards.Any(x => x.LuminaryName == LuminaryName.TheRiver);
+
+            var min = banked + player.SuperlativeScoring.Values.Where(x => x < 0).Sum();
+            var max = banked + foolsInPlay + luminariesInPlay + IllimatOkuses.Count +
+                player.SuperlativeScoring.Values.Where(x => x > 0).Sum() +
+                (riverInReach ? Math.Abs(player.SuperlativeScoring[Superlative.Frostbit]) : 0);
+
+            return (min, max);
+        }
+
+        // Returns the cards each player is seeing for the first time, so an action that keeps them can take its
+        // reveal back when it is unwound. Cards already seen before the reveal stay seen either way.
+        public List<(Player Player, Card Card)> RevealToAll(IEnumerable<Card> cards)
+        {
+            var revealed = cards.ToList();
+            var newlySeen = new List<(Player Player, Card Card)>();
+
+            foreach (var player in Players)
+            {
+                foreach (var card in revealed)
+                {
+                    if (player.SeenCards.Add(card))
+                        newlySeen.Add((player, card));
+                }
+            }
+
+            return newlySeen;
         }
 
         private static List<Player> GeneratePlayers(Dictionary<PlayerType, int> playerCounts, Random random)
@@ -49,15 +603,31 @@ namespace Illimat.Core
             return players;
         }
 
+        // Every field is dealt a luminary, so a chosen set needs at least one per field and can't repeat one.
+        private Deck<Luminary> GenerateLuminaryDeck(IReadOnlyList<LuminaryName>? luminaryNames)
+        {
+            if (luminaryNames == null) return new Deck<Luminary>(Luminary.AllLuminaries());
+
+            if (luminaryNames.Count < Fields.Length)
+                throw new ArgumentException($"{luminaryNames.Count} luminaries can't cover all {Fields.Length} fields.");
+            if (luminaryNames.Distinct().Count() < luminaryNames.Count)
+                throw new ArgumentException($"The chosen luminaries repeat, but each exists only once: {string.Join(", ", luminaryNames)}.");
+
+            return new Deck<Luminary>(luminaryNames.Select(x => new Luminary(x)).ToList());
+        }
+
         private static Deck<Card> GenerateShuffledCardDeck(Dictionary<PlayerType, int> playerCounts, Random random)
         {
-            var cardDeck = playerCounts[PlayerType.Human] + playerCounts[PlayerType.Computer] < 4 ?
-                new Deck<Card>(Card.GetCards(SuitSet.NoStars)) :
-                new Deck<Card>(Card.GetCards(SuitSet.AllSuits));
+            var cardDeck = new Deck<Card>(Card.GetCards(
+                SuitSetFor(playerCounts[PlayerType.Human] + playerCounts[PlayerType.Computer])));
 
             cardDeck.Shuffle(random);
 
             return cardDeck;
         }
+
+        // The Stars suit only comes into play with four players.
+        private static IEnumerable<Suit> SuitSetFor(int playerCount) =>
+            playerCount < 4 ? SuitSet.NoStars : SuitSet.AllSuits;
     }
 }
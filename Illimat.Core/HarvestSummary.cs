@@ -0,0 +1,57 @@
+﻿using Illimat.Core.Models;
+
+namespace Illimat.Core
+{
+    // Running totals over a harvest pile so round scoring doesn't need to rescan every card.
+    public class HarvestSummary
+    {
+        public int CardCount { get; private set; }
+        public int FoolCount { get; private set; }
+        public Dictionary<Suit, int> SuitCounts { get; } = SuitSet.AllSuits.ToDictionary(x => x, x => 0);
+        // A flag per suit for whether its Queen has been harvested; each suit has only the one Queen.
+        private readonly HashSet<Suit> queenSuits = new();
+        public IReadOnlyCollection<Suit> QueenSuits => queenSuits;
+
+        public bool HasQueen(Suit suit) => queenSuits.Contains(suit);
+
+        public static HarvestSummary FromCards(IEnumerable<Card> cards)
+        {
+            var summary = new HarvestSummary();
+
+            foreach (var card in cards)
+            {
+                summary.Add(card);
+            }
+
+            return summary;
+        }
+
+        public void Add(Card card)
+        {
+            CardCount++;
+            SuitCounts[card.Suit]++;
+            if (card.Rank == Rank.Fool) FoolCount++;
+            if (card.Rank == Rank.Queen) queenSuits.Add(card.Suit);
+        }
+
+        public void Remove(Card card)
+        {
+            CardCount--;
+            SuitCounts[card.Suit]--;
+            if (card.Rank == Rank.Fool) FoolCount--;
+            if (card.Rank == Rank.Queen) queenSuits.Remove(card.Suit);
+        }
+
+        public void Clear()
+        {
+            CardCount = 0;
+            FoolCount = 0;
+            queenSuits.Clear();
+
+            foreach (var suit in SuitCounts.Keys.ToList())
+            {
+                SuitCounts[suit] = 0;
+            }
+        }
+    }
+}
@@ -0,0 +1,18 @@
+﻿using Illimat.Core.Models;
+
+namespace Illimat.Core
+{
+    // Thrown when an action breaks the rules of the game, so callers can match on Error rather than parse Message.
+    public class IllimatException : Exception
+    {
+        public IllimatError Error { get; }
+
+        public IllimatException(IllimatError error, string message) : base(message)
+        {
+            Error = error;
+        }
+
+        // The message led by the error, for console output; ToString keeps the usual exception details and stack trace.
+        public string Summary => $"{Error}: {Message}";
+    }
+}
@@ -9,7 +9,7 @@ namespace Illimat.Core
         private bool isRevealed = false;
 
         public bool IsRevealed { get => isRevealed; set => isRevealed = value; }
-        public string Name { get => IsRevealed ? "Unknown" : LuminaryName.ToFriendlyString(); }
+        public string Name { get => IsRevealed ? LuminaryName.ToFriendlyString() : "Unknown"; }
 
         public Luminary(LuminaryName luminaryName)
         {
@@ -0,0 +1,67 @@
+﻿using Illimat.Core.Models;
+
+namespace Illimat.Core
+{
+    // Folds luminary effects into a field's seasonal capabilities. Effects apply in a fixed precedence, each able to
+    // override the ones before it, and every luminary that changes the outcome adds a line to SpecialRules:
+    //   1. The field's season, unless the field ignores seasons.
+    //   2. The Forest Queen: her field ignores its season, playing as Summer.
+    //   3. The Maiden: Winter fields can be harvested while she is face up or once she is claimed.
+    //   4. The Boat: its field can't be harvested in Winter, whatever the Maiden allows.
+    //   5. The Island: its field is cut off from play, overriding everything else.
+    public class LuminaryManager
+    {
+        private readonly GameState gameState;
+
+        public LuminaryManager(GameState gameState)
+        {
+            this.gameState = gameState;
+        }
+
+        // The season a field plays as. Summer restricts nothing, so a field that ignores its season, whether by its
+        // own setting or under the Forest Queen, plays as Summer whatever the Illimat shows. The Island leaves the
+        // season alone; it cuts the field off instead.
+        public Season EffectiveSeason(Field field) =>
+            field.IgnoreSeason || IsFaceUpIn(field, LuminaryName.TheForestQueen) ? Season.Summer : field.Season;
+
+        public FieldCapabilities EffectiveCapabilities(Field field)
+        {
+            var specialRules = new List<string>();
+            var season = EffectiveSeason(field);
+
+            if (!field.IgnoreSeason && IsFaceUpIn(field, LuminaryName.TheForestQueen))
+            {
+                specialRules.Add("The Forest Queen: this field ignores its season.");
+            }
+
+            var canSow = season != Season.Autumn;
+            var canHarvest = season != Season.Winter;
+            var canStockpile = season != Season.Spring;
+
+            if (!canHarvest && (gameState.IsLuminaryFaceUp(LuminaryName.TheMaiden) || gameState.IsLuminaryClaimed(LuminaryName.TheMaiden)))
+            {
+                canHarvest = true;
+                specialRules.Add("The Maiden: Winter fields can be harvested.");
+            }
+
+            if (gameState.IsIcebound(field))
+            {
+                canHarvest = false;
+                specialRules.Add("The Boat: iced in for Winter, so this field can't be harvested.");
+            }
+
+            if (gameState.IsIsolated(field))
+            {
+                canSow = false;
+                canHarvest = false;
+                canStockpile = false;
+                specialRules.Add("The Island: this field is cut off from play.");
+            }
+
+            return new FieldCapabilities(canSow, canHarvest, canStockpile, specialRules);
+        }
+
+        private static bool IsFaceUpIn(Field field, LuminaryName luminaryName) =>
+            field.Luminary != null && field.Luminary.IsRevealed && field.Luminary.LuminaryName == luminaryName;
+    }
+}
@@ -0,0 +1,10 @@
+﻿namespace Illimat.Core.Models
+{
+    public enum AIPersonality
+    {
+        Balanced,
+        Aggressive,
+        Conservative,
+        Opportunistic
+    }
+}
@@ -0,0 +1,21 @@
+﻿namespace Illimat.Core.Models
+{
+    public enum GameEventType
+    {
+        CardsSown,
+        CardsHarvested,
+        StockpileCreated,
+        CardExchanged,
+        FieldCleared,
+        SeasonChanged,
+        LuminaryRevealed,
+        LuminaryClaimed,
+        LuminaryDiscarded,
+        OkusCollected,
+        OkusStolen,
+        PartialDeal,
+        HiddenCardsRevealed,
+        ScoreChanged,
+        RoundEnded
+    }
+}
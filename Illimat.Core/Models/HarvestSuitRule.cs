@@ -0,0 +1,8 @@
+﻿namespace Illimat.Core.Models
+{
+    public enum HarvestSuitRule
+    {
+        Any,
+        MatchSuit
+    }
+}
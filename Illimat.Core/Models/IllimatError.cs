@@ -0,0 +1,26 @@
+﻿namespace Illimat.Core.Models
+{
+    public enum IllimatError
+    {
+        NotYourTurn,
+        WrongActor,
+        MustPlayCard,
+        CardNotInHand,
+        InvalidCardCount,
+        InvalidField,
+        SeasonBlocked,
+        SuitMismatch,
+        NoMatchingHarvest,
+        NoMatchingStockpile,
+        NotAStockpile,
+        StockpiledCard,
+        StockpileTooSoon,
+        LuminaryUnavailable,
+        AlreadyUsedThisTurn,
+        NoOkus,
+        InvalidTarget,
+        InvalidNotation,
+        AmbiguousCard,
+        OkusNotEarned
+    }
+}
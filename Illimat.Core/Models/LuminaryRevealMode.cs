@@ -0,0 +1,8 @@
+﻿namespace Illimat.Core.Models
+{
+    public enum LuminaryRevealMode
+    {
+        OnFieldClear,
+        AtDeal
+    }
+}
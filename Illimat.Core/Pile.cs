@@ -5,8 +5,14 @@ namespace Illimat.Core
 {
     public record class Pile
     {
+        // Enough for any field seen in play; a crowded field of low cards could otherwise offer thousands of harvests.
+        public const int MAX_HARVEST_COMBINATIONS = 512;
+
         public IList<Card> Cards { get; init; }
         public IList<int> Values { get; init; }
+        public Player? Creator { get; init; }
+        public int? CreatedTurn { get; init; }
+        public bool IsStockpile => Creator != null || Cards.Count > 1;
 
         public Pile(IList<Card> cards)
         {
@@ -25,7 +31,7 @@ namespace Illimat.Core
             var othersSum = othersValues.Sum();
             var foolsCount = Cards.Count - othersValues.Count();
 
-            for (int i = 0; i < foolsCount * 13; i += 13)
+            for (int i = 0; i <= foolsCount * 13; i += 13)
             {
                 result.Add(othersSum + foolsCount + i);
             }
@@ -33,6 +39,63 @@ namespace Illimat.Core
             return result;
         }
 
+        public static HashSet<int> SumValues(IEnumerable<Pile> piles)
+        {
+            var sums = new HashSet<int> { 0 };
+
+            foreach (var pile in piles)
+            {
+                sums = sums.SelectMany(sum => pile.Values, (sum, value) => sum + value).ToHashSet();
+            }
+
+            return sums;
+        }
+
+        // Whether the piles can be split into groups that each add up to the value, i.e. all harvested by one card.
+        public static bool CanPartition(IList<Pile> piles, int value)
+        {
+            if (piles.Count == 0) return true;
+            // Groups of the value can only add up to a multiple of it, which rules most sets out cheaply.
+            if (!SumValues(piles).Any(x => x % value == 0)) return false;
+
+            var first = piles[0];
+            var others = piles.Skip(1).ToList();
+
+            foreach (var companions in others.GetSubsets())
+            {
+                if (!SumValues(companions.Prepend(first)).Contains(value)) continue;
+
+                var remaining = others.Where(x => !companions.Contains(x)).ToList();
+                if (CanPartition(remaining, value)) return true;
+            }
+
+            return false;
+        }
+
+        // Every distinct set of the piles that one card could harvest together, each set splitting into groups that add
+        // up to one of the card's values. A stockpile counts as a single pile and a Fool as either 1 or 14, on the card
+        // and in the piles alike. At most MAX_HARVEST_COMBINATIONS sets are returned.
+        public static IList<IList<Pile>> HarvestCombinations(IList<Pile> piles, Card card) =>
+            HarvestCombinations(piles, card, MAX_HARVEST_COMBINATIONS).Combinations;
+
+        // The search stops once it has found the limit, reporting whether any combinations were left out.
+        public static (IList<IList<Pile>> Combinations, bool Truncated) HarvestCombinations(IList<Pile> piles, Card card, int limit)
+        {
+            if (limit < 0) throw new ArgumentOutOfRangeException(nameof(limit), "The limit can't be negative.");
+
+            var combinations = new List<IList<Pile>>();
+
+            foreach (var subset in piles.GetSubsets())
+            {
+                if (subset.Count == 0 || !card.HarvestValues().Any(value => CanPartition(subset, value))) continue;
+                if (combinations.Count == limit) return (combinations, true);
+
+                combinations.Add(subset);
+            }
+
+            return (combinations, false);
+        }
+
         public static Dictionary<int, IList<IList<Pile>>> GetPilesSetsValues(IList<Pile> piles)
         {
             var values = new Dictionary<int, IList<IList<Pile>>>();
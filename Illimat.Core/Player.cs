@@ -8,9 +8,13 @@ namespace Illimat.Core
         public PlayerType Type { get; init; }
         public int Score { get; set; }
         public List<Card> Hand { get; set; } = new();
-        public List<Card> HarvestPile { get; } = new();
+        private readonly List<Card> harvestPile = new();
+        public IReadOnlyList<Card> HarvestPile => harvestPile;
+        public HarvestSummary HarvestSummary { get; } = new();
+        public PlayerActionStats ActionStats { get; } = new();
         public List<Okus> ScorePileOkuses { get; } = new();
         public List<Luminary> ScorePileLuminaries { get; } = new();
+        public HashSet<Card> SeenCards { get; } = new();
         public Dictionary<Superlative, int> SuperlativeScoring { get; } = new Dictionary<Superlative, int> {
             {Superlative.BumperCrop, 4 },
             {Superlative.Frostbit, -2 },
@@ -22,5 +26,35 @@ namespace Illimat.Core
             Name = name;
             Type = type;
         }
+
+        // Every harvested Fool, claimed luminary and collected okus is worth a point at the end of the round.
+        public int FoolPoints => HarvestSummary.FoolCount;
+
+        public int LuminaryPoints => ScorePileLuminaries.Count;
+
+        public int OkusPoints => ScorePileOkuses.Count;
+
+        public void AddToHarvest(IEnumerable<Card> cards)
+        {
+            foreach (var card in cards)
+            {
+                harvestPile.Add(card);
+                HarvestSummary.Add(card);
+            }
+        }
+
+        public void RemoveFromHarvest(IEnumerable<Card> cards)
+        {
+            foreach (var card in cards)
+            {
+                if (harvestPile.Remove(card)) HarvestSummary.Remove(card);
+            }
+        }
+
+        public void ClearHarvest()
+        {
+            harvestPile.Clear();
+            HarvestSummary.Clear();
+        }
     }
 }
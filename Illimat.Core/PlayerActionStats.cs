@@ -0,0 +1,13 @@
+﻿namespace Illimat.Core
+{
+    // Running counts of what a player has done, kept in step with every action performed and unwound.
+    public class PlayerActionStats
+    {
+        public int Sows { get; set; }
+        public int Harvests { get; set; }
+        public int Stockpiles { get; set; }
+        // Every card a harvest put in the harvest pile, including the cards played from hand and any hidden cards.
+        public int CardsHarvested { get; set; }
+        public int FieldClears { get; set; }
+    }
+}
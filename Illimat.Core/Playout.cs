@@ -0,0 +1,123 @@
+﻿using Illimat.Core.Actions;
+using Illimat.Core.Models;
+
+namespace Illimat.Core
+{
+    // Plays a round out with uniformly random legal moves, for measuring computer players against a baseline, or with
+    // every seat played by a SimpleCpu. Only the first round can be dealt so far, so a playout ends when that round is
+    // scored rather than when the game is won, and the scores returned are that one round's. Every okus a clear earns
+    // is collected and a claimed Perfect Crime steals from a random target.
+    // Random play skips Changeling exchanges since they don't take the turn; a CPU that makes one then plays on. A seat
+    // left with no legal action stops the playout with WhyNoMove's reason rather than spinning until MAX_STEPS.
+    public static class Playout
+    {
+        // Far more steps than a round of sixty-five cards can take, so a stuck playout fails instead of spinning.
+        const int MAX_STEPS = 1000;
+
+        public static int[] RandomRoundPlayout(Game game, Random random) => Play(game, random, (state, player) =>
+        {
+            var actions = state.LegalActions(player).Where(x => x is not ChangelingExchange).ToList();
+
+            if (actions.Count == 0)
+                throw new InvalidOperationException(state.WhyNoMove(player) ?? $"{player.Name} has no legal action.");
+
+            return actions[random.Next(actions.Count)];
+        });
+
+        // The random source only picks okuses and theft targets; the CPU's own choices are fixed by the position.
+        public static int[] CpuRoundPlayout(Game game, SimpleCpu cpu, Random random) =>
+            Play(game, random, (state, player) => cpu.ChooseAction(state, player));
+
+        private static int[] Play(Game game, Random random, Func<GameState, Player, IAction> choose)
+        {
+            var state = game.State ?? throw new InvalidOperationException("The game has no state to play out.");
+
+            for (int steps = 0; steps < MAX_STEPS; steps++)
+            {
+                if (game.PerformPending() is IAction pendingAction)
+                {
+                    if (pendingAction is ScoreRound) return state.Players.Select(x => x.Score).ToArray();
+                    continue;
+                }
+
+                var player = state.ActivePlayer;
+                var action = choose(state, player);
+
+                // An exchange doesn't take the turn, and only one is allowed, so the next choice is the real move.
+                if (action is ChangelingExchange)
+                {
+                    game.Perform(action);
+                    action = choose(state, player);
+                }
+
+                game.Perform(action);
+
+                while (state.OkusCollection is (Player collector, > 0, int turn) && ReferenceEquals(collector, player) &&
+                    turn == state.TurnNumber && state.IllimatOkuses.Count > 0)
+                {
+                    game.Perform(new CollectOkus(player, state.IllimatOkuses[random.Next(state.IllimatOkuses.Count)]));
+                }
+
+                if (state.PerfectCrimeTheft is (Player thief, > 0, int theftTurn) && ReferenceEquals(thief, player) &&
+                    theftTurn == state.TurnNumber)
+                {
+                    var victims = state.Players
+                        .Where(x => !ReferenceEquals(x, player) && x.ScorePileOkuses.Count > 0)
+                        .Cast<Player?>()
+                        .Concat(state.IllimatOkuses.Count > 0 ? new Player?[] { null } : Array.Empty<Player?>())
+                        .ToList();
+
+                    if (victims.Count > 0) game.Perform(new StealOkus(player, victims[random.Next(victims.Count)]));
+                }
+
+                game.AdvanceTurn();
+            }
+
+            throw new InvalidOperationException($"The playout didn't finish within {MAX_STEPS} steps.");
+        }
+
+        // Plays a fresh game's first round per playout, each seeded from the one seed so a batch can be repeated
+        // exactly. The stats are per round, not per game.
+        public static PlayoutStats RunPlayouts(int count, int seed, GameConfig? config = null) =>
+            Run(count, seed, config, RandomRoundPlayout);
+
+        // Every seat is played by the same kind of SimpleCpu, so a fixed seed gives the same win rates every time.
+        public static PlayoutStats RunCpuPlayouts(int count, int seed, AIPersonality personality = AIPersonality.Balanced,
+            GameConfig? config = null)
+        {
+            var cpu = new SimpleCpu(personality);
+
+            return Run(count, seed, config, (game, random) => CpuRoundPlayout(game, cpu, random));
+        }
+
+        private static PlayoutStats Run(int count, int seed, GameConfig? config, Func<Game, Random, int[]> playout)
+        {
+            var random = new GameRandom(seed);
+            var totals = new int[0];
+            var wins = new int[0];
+            var turns = 0;
+
+            for (int i = 0; i < count; i++)
+            {
+                var game = new Game(seed: random.Next(), config: config);
+                var scores = playout(game, random);
+                var best = scores.Max();
+                turns += game.State!.TurnNumber;
+
+                if (i == 0)
+                {
+                    totals = new int[scores.Length];
+                    wins = new int[scores.Length];
+                }
+
+                for (int j = 0; j < scores.Length; j++)
+                {
+                    totals[j] += scores[j];
+                    if (scores[j] == best && scores.Count(x => x == best) == 1) wins[j]++;
+                }
+            }
+
+            return new PlayoutStats(count, totals, wins) { TotalTurns = turns };
+        }
+    }
+}
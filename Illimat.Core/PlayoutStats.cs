@@ -0,0 +1,25 @@
+﻿namespace Illimat.Core
+{
+    // Totals over a batch of playouts, by seat. Each playout is a single round, so wins and scores are per round: a
+    // round with tied top scores counts as a win for nobody, and a round's length is the number of turns taken in it.
+    public record class PlayoutStats
+    {
+        public int Rounds { get; init; }
+        public IReadOnlyList<int> TotalScores { get; init; }
+        public IReadOnlyList<int> Wins { get; init; }
+        public int TotalTurns { get; init; }
+
+        public PlayoutStats(int rounds, IReadOnlyList<int> totalScores, IReadOnlyList<int> wins)
+        {
+            Rounds = rounds;
+            TotalScores = totalScores;
+            Wins = wins;
+        }
+
+        public IReadOnlyList<double> AverageScores => TotalScores.Select(x => Rounds == 0 ? 0 : (double)x / Rounds).ToList();
+
+        public IReadOnlyList<double> WinRates => Wins.Select(x => Rounds == 0 ? 0 : (double)x / Rounds).ToList();
+
+        public double AverageTurns => Rounds == 0 ? 0 : (double)TotalTurns / Rounds;
+    }
+}
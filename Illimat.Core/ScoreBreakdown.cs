@@ -0,0 +1,25 @@
+﻿using Illimat.Core.Models;
+
+namespace Illimat.Core
+{
+    // One player's points for the round so far, source by source.
+    public record class ScoreBreakdown
+    {
+        public int BumperCrop { get; init; }
+        public int Sunkissed { get; init; }
+        public int Frostbit { get; init; }
+        public int Fools { get; init; }
+        public int Luminaries { get; init; }
+        public int Okuses { get; init; }
+        public int Total => BumperCrop + Sunkissed + Frostbit + Fools + Luminaries + Okuses;
+        // Superlatives the player shared the lead for: a voided bonus, or a Frostbit taken alongside someone else.
+        public IReadOnlyList<Superlative> TiedSuperlatives { get; init; } = new List<Superlative>();
+
+        public virtual bool Equals(ScoreBreakdown? other) => other is not null &&
+            BumperCrop == other.BumperCrop && Sunkissed == other.Sunkissed && Frostbit == other.Frostbit &&
+            Fools == other.Fools && Luminaries == other.Luminaries && Okuses == other.Okuses &&
+            TiedSuperlatives.SequenceEqual(other.TiedSuperlatives);
+
+        public override int GetHashCode() => HashCode.Combine(BumperCrop, Sunkissed, Frostbit, Fools, Luminaries, Okuses);
+    }
+}
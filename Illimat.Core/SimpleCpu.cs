@@ -0,0 +1,95 @@
+﻿using Illimat.Core.Actions;
+using Illimat.Core.Extensions;
+using Illimat.Core.Models;
+
+namespace Illimat.Core
+{
+    // A computer player that looks one move ahead. Every legal action is scored and the best taken, with ties going
+    // to whichever LegalActions lists first. That order is fixed by the position, so any two players with the same
+    // personality choose the same action from the same position, with nothing left to chance. Clearing a field (and
+    // so winning an okus) beats any other harvest, bigger harvests beat smaller ones, stockpiles beat sows, and sows
+    // are marked down for every new harvest value they leave in the field for the next player. A personality shifts
+    // those weights without changing the order of play. A face card that turns the Illimat also scores the change the
+    // new seasons make to the player's tempo.
+    // Choosing leaves the game as it found it, so ChooseAction can also suggest a move to a human player. A player with
+    // no legal action gets WhyNoMove's reason as an exception instead of a move.
+    public class SimpleCpu
+    {
+        public AIPersonality Personality { get; }
+        private int ClearedFieldScore { get; }
+        private int HarvestedCardScore { get; }
+        private int StockpileScore { get; }
+        private int StockpiledCardScore { get; }
+        private int OpenedValuePenalty { get; }
+        private int TempoScore { get; }
+
+        public SimpleCpu(AIPersonality personality = AIPersonality.Balanced)
+        {
+            Personality = personality;
+
+            // Aggressive play grabs the biggest harvest on offer; conservative play would rather build a stockpile
+            // and is more careful not to feed the next player and to keep the seasons on its side; opportunistic play
+            // lives for clearing fields.
+            (ClearedFieldScore, HarvestedCardScore, StockpileScore, StockpiledCardScore, OpenedValuePenalty, TempoScore) = personality switch
+            {
+                AIPersonality.Aggressive => (60, 25, 0, 1, 0, 1),
+                AIPersonality.Conservative => (100, 5, 30, 5, 5, 3),
+                AIPersonality.Opportunistic => (200, 10, 5, 2, 1, 1),
+                _ => (100, 10, 5, 2, 1, 2)
+            };
+        }
+
+        public IAction ChooseAction(GameState gameState, Player player)
+        {
+            var actions = gameState.LegalActions(player);
+
+            if (actions.Count == 0)
+                throw new InvalidOperationException(gameState.WhyNoMove(player) ?? $"{player.Name} has no legal action.");
+
+            return actions
+                .Select((action, index) => (Action: action, Index: index, Score: EvaluateAction(gameState, action)))
+                .OrderByDescending(x => x.Score)
+                .ThenBy(x => x.Index)
+                .First().Action;
+        }
+
+        public int EvaluateAction(GameState gameState, IAction action) => TempoScore * TempoGain(gameState, action) + action switch
+        {
+            HarvestCards x => (x.Piles.Count == x.Field.Piles.Count ? ClearedFieldScore : 0) +
+                HarvestedCardScore * x.Piles.Concat(x.OppositePiles).Sum(pile => pile.Cards.Count),
+            StockpileCards x => StockpileScore + StockpiledCardScore * x.Piles.Sum(pile => pile.Cards.Count),
+            SowCards x => -OpenedValuePenalty * OpenedValues(x.Field, x.Cards).Count,
+            _ => 0
+        };
+
+        // How much the player's tempo would change with the Illimat turned for the last face card the action plays,
+        // or nothing if it plays none.
+        private static int TempoGain(GameState gameState, IAction action)
+        {
+            var field = action.TargetField();
+            if (field == null || action.Actor is not Player player) return 0;
+
+            var turn = action.PlayedCards()
+                .Select(card => ChangeSeason.ForFaceCard(player, card, gameState, field))
+                .LastOrDefault(x => x != null);
+            if (turn == null || gameState.Fields[turn.FieldIndex].Season == turn.Season) return 0;
+
+            return gameState.TempoAfterTurning(player, gameState.Fields[turn.FieldIndex], turn.Season) - gameState.Tempo(player);
+        }
+
+        // The harvest values the field would offer once the cards are sown that it doesn't offer already.
+        private static HashSet<int> OpenedValues(Field field, IEnumerable<Card> cards)
+        {
+            var before = HarvestableValues(field.Piles);
+            var after = HarvestableValues(field.Piles.Concat(cards.Select(x => new Pile(new List<Card> { x }))).ToList());
+
+            after.ExceptWith(before);
+            return after;
+        }
+
+        private static HashSet<int> HarvestableValues(IList<Pile> piles) => piles.GetSubsets()
+            .Where(x => x.Count > 0)
+            .SelectMany(x => Pile.SumValues(x))
+            .ToHashSet();
+    }
+}
@@ -0,0 +1,14 @@
+﻿namespace Illimat.Core
+{
+    public record class StockpileOption
+    {
+        public IList<Pile> Piles { get; init; }
+        public int Value { get; init; }
+
+        public StockpileOption(IList<Pile> piles, int value)
+        {
+            Piles = piles;
+            Value = value;
+        }
+    }
+}